@@ -9,14 +9,18 @@ pub struct Schema {
 }
 impl Schema {
     pub(crate) fn download() -> Schema {
-        if cfg!(feature = "vendored") {
+        let mut schema: Schema = if cfg!(feature = "vendored") {
             serde_json::from_str(include_str!("tmdb-api.json")).unwrap()
         } else {
             reqwest::blocking::get("https://developer.themoviedb.org/openapi/tmdb-api.json")
                 .unwrap()
                 .json()
                 .unwrap()
-        }
+        };
+        schema.paths.fix_separated_list_parameters();
+        schema.paths.add_missing_page_parameters();
+        schema.paths.fix_fractional_fields();
+        schema
     }
 }
 
@@ -56,6 +60,72 @@ impl<'a> IntoIterator for &'a SchemaPaths {
     }
 }
 
+impl SchemaPaths {
+    /// Some parameters are documented as comma (`AND`) or pipe (`OR`)
+    /// separated lists, but typed as a single integer. Those are turned into
+    /// strings, so that the list form can actually be sent.
+    fn fix_separated_list_parameters(&mut self) {
+        for parameter in self
+            .0
+            .values_mut()
+            .filter_map(|p| p.get.as_mut())
+            .flat_map(|g| g.parameters.iter_mut())
+        {
+            if parameter.is_separated_list()
+                && matches!(
+                    parameter.schema,
+                    TypeSchema::Tagged(TaggedTypeSchema::Integer { .. })
+                )
+            {
+                parameter.schema = TypeSchema::Tagged(TaggedTypeSchema::String { default: None });
+            }
+        }
+    }
+
+    /// Response types are derived from example values, so a field like
+    /// `vote_average` is typed as an integer on some routes, where the
+    /// example happened to be a whole number. Those fields are made numbers.
+    fn fix_fractional_fields(&mut self) {
+        for (path, fields) in FRACTIONAL_FIELDS {
+            let Some(get) = self.0.get_mut(*path).and_then(|p| p.get.as_mut()) else {
+                continue;
+            };
+            for content in get
+                .responses
+                .values_mut()
+                .flat_map(|r| r.content.values_mut())
+            {
+                content.schema.visit_properties(&mut |name, property| {
+                    if fields.contains(&name)
+                        && matches!(
+                            property,
+                            TypeSchema::Tagged(TaggedTypeSchema::Integer { .. })
+                        )
+                    {
+                        *property = TypeSchema::Tagged(TaggedTypeSchema::Number { default: None });
+                    }
+                });
+            }
+        }
+    }
+
+    /// Some routes return paginated results, but do not document the `page`
+    /// parameter. So it is added here.
+    fn add_missing_page_parameters(&mut self) {
+        for get in self.0.values_mut().filter_map(|p| p.get.as_mut()) {
+            if get.is_paginated() && !get.parameters.iter().any(|p| p.name == "page") {
+                get.parameters.push(PathRouteParameter {
+                    name: "page".into(),
+                    r#in: ParameterLocation::Query,
+                    schema: TypeSchema::Tagged(TaggedTypeSchema::Integer { default: Some(1) }),
+                    description: None,
+                    required: false,
+                });
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SchemaPath {
     pub get: Option<PathRoute>,
@@ -73,6 +143,20 @@ pub struct PathRoute {
     pub deprecated: bool,
 }
 
+impl PathRoute {
+    pub fn is_paginated(&self) -> bool {
+        self.responses
+            .values()
+            .flat_map(|r| r.content.values())
+            .any(|c| match &c.schema {
+                TypeSchema::Tagged(TaggedTypeSchema::Object { properties }) => {
+                    properties.contains_key("total_pages")
+                }
+                _ => false,
+            })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PathRouteResponse {
     pub description: String,
@@ -94,6 +178,40 @@ pub struct PathRouteParameter {
     pub required: bool,
 }
 
+/// Fields, that are typed as integers on these routes, but are numbers
+/// elsewhere and in real responses.
+const FRACTIONAL_FIELDS: &[(&str, &[&str])] = &[
+    ("/3/account/{account_id}/rated/movies", &["rating"]),
+    ("/3/account/{account_id}/rated/tv", &["rating"]),
+    ("/3/account/{account_id}/rated/tv/episodes", &["rating"]),
+    ("/3/discover/tv", &["vote_average"]),
+    ("/3/list/{list_id}", &["vote_average"]),
+    ("/3/movie/latest", &["popularity", "vote_average"]),
+    ("/3/movie/upcoming", &["vote_average"]),
+    ("/3/person/latest", &["popularity"]),
+    ("/3/review/{review_id}", &["rating"]),
+    ("/3/tv/airing_today", &["vote_average"]),
+    ("/3/tv/latest", &["popularity", "vote_average"]),
+    ("/3/tv/on_the_air", &["vote_average"]),
+    ("/3/tv/popular", &["vote_average"]),
+    ("/3/tv/{series_id}", &["vote_average"]),
+    ("/3/tv/{series_id}/reviews", &["rating"]),
+    ("/3/tv/{series_id}/similar", &["vote_average"]),
+];
+
+/// Parameters, that accept separated lists, but are not documented as such.
+const UNDOCUMENTED_SEPARATED_LISTS: &[&str] = &["with_networks"];
+
+impl PathRouteParameter {
+    pub fn is_separated_list(&self) -> bool {
+        UNDOCUMENTED_SEPARATED_LISTS.contains(&self.name.as_str())
+            || self
+                .description
+                .as_deref()
+                .is_some_and(|d| d.contains("separated query"))
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ParameterLocation {
@@ -118,6 +236,22 @@ pub enum TypeSchema {
     Empty(HashMap<String, String>),
 }
 
+impl TypeSchema {
+    /// Calls `f` with every property of every object in this schema.
+    fn visit_properties(&mut self, f: &mut impl FnMut(&str, &mut TypeSchema)) {
+        match self {
+            TypeSchema::Tagged(TaggedTypeSchema::Object { properties }) => {
+                for (name, property) in properties {
+                    f(name, property);
+                    property.visit_properties(f);
+                }
+            }
+            TypeSchema::Tagged(TaggedTypeSchema::Array { items }) => items.visit_properties(f),
+            _ => {}
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type")]
 #[serde(rename_all = "lowercase")]
@@ -0,0 +1,324 @@
+//! The favorites, watchlist, ratings and lists of a logged in user. Every
+//! call needs a [`Session`].
+
+use std::{borrow::Cow, marker::PhantomData};
+
+use reqwest::Method;
+use serde_json::json;
+use tmdb_easy_raw::types::{
+    AccountDetailsResponse200, AccountFavoriteTvResponse200Results,
+    AccountGetFavoritesResponse200Results, AccountListsResponse200Results,
+    AccountRatedMoviesResponse200Results, AccountRatedTvEpisodesResponse200Results,
+    AccountRatedTvResponse200Results, AccountWatchlistMoviesResponse200Results,
+    AccountWatchlistTvResponse200Results,
+};
+
+use crate::{
+    MediaType, MovieOrTvLike,
+    authentication::Session,
+    client::TmdbClient,
+    discover::SortOrder,
+    error::Error,
+    impl_movie_or_tv_like,
+    language::Language,
+    localization::Locale,
+    page::{Page, PagedBuilder, PagedResponse},
+    region::Region,
+};
+
+/// The settings of a tmdb user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Account {
+    pub id: u64,
+    pub username: String,
+    /// Empty, if the user did not set one.
+    pub name: String,
+    pub include_adult: bool,
+    pub language: Option<Language>,
+    pub region: Option<Region>,
+    /// An avatar uploaded to tmdb, `None` if the user has none or uses
+    /// gravatar.
+    pub avatar_path: Option<String>,
+}
+
+impl From<AccountDetailsResponse200> for Account {
+    fn from(value: AccountDetailsResponse200) -> Self {
+        Self {
+            id: value.id as _,
+            username: value.username,
+            name: value.name,
+            include_adult: value.include_adult,
+            language: value.iso_639_1.parse().ok(),
+            region: value.iso_3166_1.parse().ok(),
+            avatar_path: Some(value.avatar.tmdb.avatar_path).filter(|path| !path.is_empty()),
+        }
+    }
+}
+
+pub type AccountListResponse<'a, T> = PagedResponse<AccountListBuilder<'a, T>>;
+
+/// Lists the favorites, watchlist, ratings or lists of an account.
+pub struct AccountListBuilder<'a, T> {
+    client: &'a TmdbClient,
+    session: &'a Session,
+    source: &'static str,
+    path: String,
+    language: Option<Cow<'a, str>>,
+    sort_order: Option<SortOrder>,
+    page: Option<i64>,
+    items: PhantomData<T>,
+}
+
+impl<'a, T: serde::de::DeserializeOwned> AccountListBuilder<'a, T> {
+    fn new(client: &'a TmdbClient, session: &'a Session, source: &'static str, list: &str) -> Self {
+        Self {
+            client,
+            session,
+            source,
+            path: format!("/3/account/{}/{list}", session.account_id),
+            language: client.language.clone(),
+            sort_order: None,
+            page: None,
+            items: PhantomData,
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    /// Sorts by the time the title was added, newest first by default.
+    pub fn with_sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    pub fn with_page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn search(self) -> Result<AccountListResponse<'a, T>, Error> {
+        PagedResponse::load(self)
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> PagedBuilder for AccountListBuilder<'_, T> {
+    type Page = Page<T>;
+
+    fn fetch_page(&self) -> Result<Self::Page, Error> {
+        let mut query = vec![("session_id", self.session.id.clone())];
+        if let Some(language) = &self.language {
+            query.push(("language", language.to_string()));
+        }
+        if let Some(sort_order) = self.sort_order {
+            query.push(("sort_by", format!("created_at.{sort_order}")));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        self.client
+            .send_json(Method::GET, self.source, &self.path, &query, None)
+    }
+
+    fn set_page(&mut self, page: i64) {
+        self.page = Some(page);
+    }
+}
+
+/// The account of a [`Session`].
+pub struct AccountBuilder<'a> {
+    client: &'a TmdbClient,
+    session: &'a Session,
+}
+
+impl<'a> AccountBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, session: &'a Session) -> Self {
+        Self { client, session }
+    }
+
+    pub fn load(&self) -> Result<Account, Error> {
+        let response: AccountDetailsResponse200 = self.client.send_json(
+            Method::GET,
+            "https://api.themoviedb.org/3/account/{account_id}",
+            &format!("/3/account/{}", self.session.account_id),
+            &[("session_id", self.session.id.clone())],
+            None,
+        )?;
+        Ok(response.into())
+    }
+
+    pub fn favorite_movies(&self) -> AccountListBuilder<'a, AccountGetFavoritesResponse200Results> {
+        AccountListBuilder::new(
+            self.client,
+            self.session,
+            "https://api.themoviedb.org/3/account/{account_id}/favorite/movies",
+            "favorite/movies",
+        )
+    }
+
+    pub fn favorite_tv(&self) -> AccountListBuilder<'a, AccountFavoriteTvResponse200Results> {
+        AccountListBuilder::new(
+            self.client,
+            self.session,
+            "https://api.themoviedb.org/3/account/{account_id}/favorite/tv",
+            "favorite/tv",
+        )
+    }
+
+    pub fn watchlist_movies(
+        &self,
+    ) -> AccountListBuilder<'a, AccountWatchlistMoviesResponse200Results> {
+        AccountListBuilder::new(
+            self.client,
+            self.session,
+            "https://api.themoviedb.org/3/account/{account_id}/watchlist/movies",
+            "watchlist/movies",
+        )
+    }
+
+    pub fn watchlist_tv(&self) -> AccountListBuilder<'a, AccountWatchlistTvResponse200Results> {
+        AccountListBuilder::new(
+            self.client,
+            self.session,
+            "https://api.themoviedb.org/3/account/{account_id}/watchlist/tv",
+            "watchlist/tv",
+        )
+    }
+
+    pub fn rated_movies(&self) -> AccountListBuilder<'a, AccountRatedMoviesResponse200Results> {
+        AccountListBuilder::new(
+            self.client,
+            self.session,
+            "https://api.themoviedb.org/3/account/{account_id}/rated/movies",
+            "rated/movies",
+        )
+    }
+
+    pub fn rated_tv(&self) -> AccountListBuilder<'a, AccountRatedTvResponse200Results> {
+        AccountListBuilder::new(
+            self.client,
+            self.session,
+            "https://api.themoviedb.org/3/account/{account_id}/rated/tv",
+            "rated/tv",
+        )
+    }
+
+    pub fn rated_episodes(
+        &self,
+    ) -> AccountListBuilder<'a, AccountRatedTvEpisodesResponse200Results> {
+        AccountListBuilder::new(
+            self.client,
+            self.session,
+            "https://api.themoviedb.org/3/account/{account_id}/rated/tv/episodes",
+            "rated/tv/episodes",
+        )
+    }
+
+    /// The lists the user created.
+    pub fn lists(&self) -> AccountListBuilder<'a, AccountListsResponse200Results> {
+        AccountListBuilder::new(
+            self.client,
+            self.session,
+            "https://api.themoviedb.org/3/account/{account_id}/lists",
+            "lists",
+        )
+    }
+
+    pub fn add_favorite(&self, item: &(impl MovieOrTvLike + ?Sized)) -> Result<(), Error> {
+        self.mark(AccountMark::Favorite, item, true)
+    }
+
+    pub fn remove_favorite(&self, item: &(impl MovieOrTvLike + ?Sized)) -> Result<(), Error> {
+        self.mark(AccountMark::Favorite, item, false)
+    }
+
+    pub fn add_to_watchlist(&self, item: &(impl MovieOrTvLike + ?Sized)) -> Result<(), Error> {
+        self.mark(AccountMark::Watchlist, item, true)
+    }
+
+    pub fn remove_from_watchlist(&self, item: &(impl MovieOrTvLike + ?Sized)) -> Result<(), Error> {
+        self.mark(AccountMark::Watchlist, item, false)
+    }
+
+    fn mark(
+        &self,
+        mark: AccountMark,
+        item: &(impl MovieOrTvLike + ?Sized),
+        value: bool,
+    ) -> Result<(), Error> {
+        let (source, list) = match mark {
+            AccountMark::Favorite => (
+                "https://api.themoviedb.org/3/account/{account_id}/favorite",
+                "favorite",
+            ),
+            AccountMark::Watchlist => (
+                "https://api.themoviedb.org/3/account/{account_id}/watchlist",
+                "watchlist",
+            ),
+        };
+        let _: serde_json::Value = self.client.send_json(
+            Method::POST,
+            source,
+            &format!("/3/account/{}/{list}", self.session.account_id),
+            &[("session_id", self.session.id.clone())],
+            Some(&mark_body(list, item.media_type(), item.id(), value)),
+        )?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AccountMark {
+    Favorite,
+    Watchlist,
+}
+
+/// E.g. `{"media_type": "movie", "media_id": 550, "favorite": true}`.
+fn mark_body(list: &str, media_type: MediaType, id: u64, value: bool) -> serde_json::Value {
+    let media_type = match media_type {
+        MediaType::Movie => "movie",
+        MediaType::Tv => "tv",
+    };
+    let mut body = json!({ "media_type": media_type, "media_id": id });
+    body[list] = value.into();
+    body
+}
+
+impl_movie_or_tv_like!(AccountGetFavoritesResponse200Results, MediaType::Movie);
+
+impl_movie_or_tv_like!(AccountWatchlistMoviesResponse200Results, MediaType::Movie);
+
+impl_movie_or_tv_like!(AccountRatedMoviesResponse200Results, MediaType::Movie);
+
+impl_movie_or_tv_like!(AccountFavoriteTvResponse200Results, MediaType::Tv);
+
+impl_movie_or_tv_like!(AccountWatchlistTvResponse200Results, MediaType::Tv);
+
+impl_movie_or_tv_like!(AccountRatedTvResponse200Results, MediaType::Tv);
+
+#[test]
+fn account_lists_and_marks() {
+    let account: AccountDetailsResponse200 = serde_json::from_str(
+        r#"{"id": 548, "username": "travisbell", "name": "Travis Bell", "include_adult": false,
+            "iso_639_1": "en", "iso_3166_1": "CA", "avatar": {"gravatar": {"hash": "c9e9fc152ee756a900db85757c29815d"}, "tmdb": {"avatar_path": null}}}"#,
+    )
+    .unwrap();
+    let account = Account::from(account);
+    assert_eq!(account.region, Some("CA".parse().unwrap()));
+    assert_eq!(account.avatar_path, None);
+
+    let page: Page<AccountRatedMoviesResponse200Results> = serde_json::from_str(
+        r#"{"page": 1, "total_pages": 1, "total_results": 1, "results": [
+            {"id": 550, "title": "Fight Club", "poster_path": "/pB8BM7pdSp6B6Ih7QZ4DrQ3PmJK.jpg", "rating": 9.5}
+        ]}"#,
+    )
+    .unwrap();
+    let movie = &page.results[0];
+    assert_eq!(movie.rating, 9.5);
+    assert_eq!(
+        mark_body("watchlist", movie.media_type(), movie.id(), false),
+        json!({"media_type": "movie", "media_id": 550, "watchlist": false})
+    );
+}
@@ -0,0 +1,205 @@
+//! Sessions for calls on behalf of a tmdb user. A user session needs a
+//! request token, that the user approved, either on the tmdb website or by
+//! logging in with their password. Guest sessions need no user, but can only
+//! rate.
+
+use reqwest::Method;
+use serde_json::json;
+use tmdb_easy_raw::types::{
+    AuthenticationCreateGuestSessionResponse200, AuthenticationCreateRequestTokenResponse200,
+};
+
+use crate::{client::TmdbClient, date::DateTime, error::Error};
+
+/// A temporary token, that becomes usable for creating a [`Session`] once
+/// the user approved it. Tokens expire after 60 minutes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestToken {
+    pub token: String,
+    pub expires_at: Option<DateTime>,
+}
+
+impl From<AuthenticationCreateRequestTokenResponse200> for RequestToken {
+    fn from(value: AuthenticationCreateRequestTokenResponse200) -> Self {
+        Self {
+            token: value.request_token,
+            expires_at: value.expires_at.parse().ok(),
+        }
+    }
+}
+
+impl RequestToken {
+    pub(crate) fn fetch(client: &TmdbClient) -> Result<Self, Error> {
+        let response: AuthenticationCreateRequestTokenResponse200 = client.send_json(
+            Method::GET,
+            "https://api.themoviedb.org/3/authentication/token/new",
+            "/3/authentication/token/new",
+            &[],
+            None,
+        )?;
+        Ok(response.into())
+    }
+
+    /// The page, where the user approves the token. tmdb sends them to
+    /// `redirect_to` afterwards, if given.
+    pub fn approval_url(&self, redirect_to: Option<&str>) -> String {
+        let mut url = reqwest::Url::parse("https://www.themoviedb.org/authenticate/")
+            .expect("the url is valid")
+            .join(&self.token)
+            .expect("tokens are url safe");
+        if let Some(redirect_to) = redirect_to {
+            url.query_pairs_mut()
+                .append_pair("redirect_to", redirect_to);
+        }
+        url.to_string()
+    }
+
+    /// Approves the token with the username and password of the user,
+    /// instead of the website.
+    pub fn validate_with_login(
+        self,
+        client: &TmdbClient,
+        username: &str,
+        password: &str,
+    ) -> Result<Self, Error> {
+        // The generated crate only has the GET endpoints, but the answer is
+        // the same as for a new token.
+        let response: AuthenticationCreateRequestTokenResponse200 = client.send_json(
+            Method::POST,
+            "https://api.themoviedb.org/3/authentication/token/validate_with_login",
+            "/3/authentication/token/validate_with_login",
+            &[],
+            Some(&json!({
+                "username": username,
+                "password": password,
+                "request_token": self.token,
+            })),
+        )?;
+        Ok(response.into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+struct RawSession {
+    session_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+struct RawAccount {
+    id: i64,
+    username: String,
+}
+
+/// A logged in user. Keep [`Session::id`] to restore the session later with
+/// [`TmdbClient::session`], it does not expire until it is deleted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    pub id: String,
+    pub account_id: u64,
+    pub username: String,
+}
+
+impl Session {
+    pub(crate) fn restore(client: &TmdbClient, id: impl Into<String>) -> Result<Self, Error> {
+        let id = id.into();
+        let account: RawAccount = client.send_json(
+            Method::GET,
+            "https://api.themoviedb.org/3/account/{account_id}",
+            "/3/account",
+            &[("session_id", id.clone())],
+            None,
+        )?;
+        Ok(Self {
+            id,
+            account_id: account.id as _,
+            username: account.username,
+        })
+    }
+
+    pub(crate) fn create(client: &TmdbClient, token: &RequestToken) -> Result<Self, Error> {
+        let response: RawSession = client.send_json(
+            Method::POST,
+            "https://api.themoviedb.org/3/authentication/session/new",
+            "/3/authentication/session/new",
+            &[],
+            Some(&json!({ "request_token": token.token })),
+        )?;
+        Self::restore(client, response.session_id)
+    }
+
+    pub(crate) fn from_v4_token(client: &TmdbClient, access_token: &str) -> Result<Self, Error> {
+        let response: RawSession = client.send_json(
+            Method::POST,
+            "https://api.themoviedb.org/3/authentication/session/convert/4",
+            "/3/authentication/session/convert/4",
+            &[],
+            Some(&json!({ "access_token": access_token })),
+        )?;
+        Self::restore(client, response.session_id)
+    }
+
+    /// Logs the user out. The session id can not be used afterwards.
+    pub fn delete(self, client: &TmdbClient) -> Result<(), Error> {
+        let _: serde_json::Value = client.send_json(
+            Method::DELETE,
+            "https://api.themoviedb.org/3/authentication/session",
+            "/3/authentication/session",
+            &[],
+            Some(&json!({ "session_id": self.id })),
+        )?;
+        Ok(())
+    }
+}
+
+/// A session without a user. It can rate movies, tv series and episodes and
+/// is deleted by tmdb after 60 minutes without use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuestSession {
+    pub id: String,
+    pub expires_at: Option<DateTime>,
+}
+
+impl From<AuthenticationCreateGuestSessionResponse200> for GuestSession {
+    fn from(value: AuthenticationCreateGuestSessionResponse200) -> Self {
+        Self {
+            id: value.guest_session_id,
+            expires_at: value.expires_at.parse().ok(),
+        }
+    }
+}
+
+impl GuestSession {
+    pub(crate) fn fetch(client: &TmdbClient) -> Result<Self, Error> {
+        let response: AuthenticationCreateGuestSessionResponse200 = client.send_json(
+            Method::GET,
+            "https://api.themoviedb.org/3/authentication/guest_session/new",
+            "/3/authentication/guest_session/new",
+            &[],
+            None,
+        )?;
+        Ok(response.into())
+    }
+}
+
+#[test]
+fn request_token_approval() {
+    let token: AuthenticationCreateRequestTokenResponse200 = serde_json::from_str(
+        r#"{"success": true, "expires_at": "2016-08-26 17:04:39 UTC", "request_token": "ff5c7eeb5a8870efe3cd7fc5c282cffd26800ecd"}"#,
+    )
+    .unwrap();
+    let token = RequestToken::from(token);
+    assert_eq!(token.expires_at.unwrap().hour, 17);
+    assert_eq!(
+        token.approval_url(Some("http://localhost:8080/approved")),
+        "https://www.themoviedb.org/authenticate/ff5c7eeb5a8870efe3cd7fc5c282cffd26800ecd?redirect_to=http%3A%2F%2Flocalhost%3A8080%2Fapproved"
+    );
+    let guest: AuthenticationCreateGuestSessionResponse200 = serde_json::from_str(
+        r#"{"success": true, "guest_session_id": "1ce82ec1223641636ad4a60b07de3581", "expires_at": "2016-08-27 16:26:40 UTC"}"#,
+    )
+    .unwrap();
+    let guest = GuestSession::from(guest);
+    assert_eq!(guest.id, "1ce82ec1223641636ad4a60b07de3581");
+    assert_eq!(guest.expires_at.unwrap().date.day, 27);
+}
@@ -0,0 +1,376 @@
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use tmdb_easy_raw::types::{MovieReleaseDatesResponse200, TvSeriesContentRatingsResponse200};
+
+use crate::{
+    MediaType, client::TmdbClient, date::Date, error::Error, movie::ReleaseType, region::Region,
+};
+
+/// A certification like `PG-13` or `FSK 16`, as listed by tmdb for a country.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct Certification {
+    pub certification: String,
+    #[serde(default)]
+    pub meaning: String,
+    /// Position in the list of the country, higher is more restrictive.
+    #[serde(default)]
+    pub order: i64,
+}
+
+impl Certification {
+    /// The minimum age, if the meaning or the certification itself states
+    /// one, e.g. `18` for `NC-17` ("No one 17 and under admitted."), `17` for
+    /// `R` ("Under 17 requires accompanying parent ...") or `16` for `16`.
+    pub fn age(&self) -> Option<u8> {
+        Self::age_in_meaning(&self.meaning).or_else(|| {
+            let digits: String = self
+                .certification
+                .chars()
+                .skip_while(|c| !c.is_ascii_digit())
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            digits.parse().ok()
+        })
+    }
+
+    /// Reads phrases like "17 and under admitted", "under 13", "younger than
+    /// 12", "age 7 and above" or "all ages" from the meaning. The more
+    /// specific phrases win, so the "adult guardian 21 or older" of `R` does
+    /// not override its "Under 17".
+    fn age_in_meaning(meaning: &str) -> Option<u8> {
+        let meaning = meaning.to_ascii_lowercase();
+        let mut words: Vec<&str> = meaning
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect();
+        words.extend(["", ""]);
+        let age = |word: &str| word.parse::<u8>().ok();
+        words
+            .windows(3)
+            .filter_map(|phrase| match phrase {
+                [n, "and", "under"] => Some((0, age(n)?.saturating_add(1))),
+                ["under", n, _] | ["younger", "than", n] => Some((1, age(n)?)),
+                [n, "and" | "or", "above" | "over" | "older"] => Some((2, age(n)?)),
+                ["all", "ages", _] => Some((3, 0)),
+                _ => None,
+            })
+            .min_by_key(|(specificity, _)| *specificity)
+            .map(|(_, age)| age)
+    }
+
+    /// Whether this entry marks titles, that were not rated, e.g. `NR` in
+    /// the US. Such entries say nothing about the age.
+    pub fn is_unrated(&self) -> bool {
+        let meaning = self.meaning.to_ascii_lowercase();
+        matches!(
+            self.certification.to_ascii_uppercase().as_str(),
+            "NR" | "UR" | "UNRATED" | "NOT RATED" | "EXEMPT"
+        ) || meaning.starts_with("not rated")
+            || meaning.starts_with("unrated")
+    }
+}
+
+/// All certifications of movies or tv series, per country.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+pub struct Certifications {
+    pub certifications: BTreeMap<Region, Vec<Certification>>,
+}
+
+impl Certifications {
+    pub(crate) fn fetch(client: &TmdbClient, media_type: MediaType) -> Result<Self, Error> {
+        match media_type {
+            MediaType::Movie => client.get_json(
+                "https://api.themoviedb.org/3/certification/movie/list",
+                "/3/certification/movie/list",
+                &[],
+            ),
+            MediaType::Tv => client.get_json(
+                "https://api.themoviedb.org/3/certification/tv/list",
+                "/3/certification/tv/list",
+                &[],
+            ),
+        }
+    }
+
+    pub fn region(&self, region: Region) -> &[Certification] {
+        self.certifications
+            .get(&region)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, region: Region, certification: &str) -> Option<&Certification> {
+        self.region(region)
+            .iter()
+            .find(|c| c.certification.eq_ignore_ascii_case(certification.trim()))
+    }
+
+    /// Compares two certifications of the same country by how restrictive
+    /// they are.
+    pub fn compare(&self, region: Region, a: &str, b: &str) -> Option<Ordering> {
+        Some(self.get(region, a)?.order.cmp(&self.get(region, b)?.order))
+    }
+
+    /// The minimum age for a certification. Certifications without an age
+    /// in their meaning or name use the age of the next more restrictive one,
+    /// e.g. `PG` the `13` of `PG-13`, so the age is never too low. Unrated
+    /// entries like `NR`, and rated ones without a more restrictive age, have
+    /// no age.
+    pub fn minimum_age(&self, region: Region, certification: &str) -> Option<u8> {
+        let found = self.get(region, certification)?;
+        if found.is_unrated() {
+            return None;
+        }
+        found.age().or_else(|| {
+            self.region(region)
+                .iter()
+                .filter(|c| !c.is_unrated() && c.order > found.order)
+                .filter_map(|c| Some((c.order, c.age()?)))
+                .min_by_key(|(order, _)| *order)
+                .map(|(_, age)| age)
+        })
+    }
+}
+
+/// A single release of a movie in a country.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    pub release_type: ReleaseType,
+    pub date: Option<Date>,
+    /// Empty, if the release is not certified.
+    pub certification: String,
+    pub language: String,
+    pub note: String,
+}
+
+/// The releases of a movie, per country.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReleaseDates {
+    pub results: BTreeMap<Region, Vec<Release>>,
+}
+
+impl From<MovieReleaseDatesResponse200> for ReleaseDates {
+    /// Countries and release types unknown to this crate are skipped.
+    fn from(value: MovieReleaseDatesResponse200) -> Self {
+        let results = value
+            .results
+            .into_iter()
+            .filter_map(|country| {
+                let region = country.iso_3166_1.parse().ok()?;
+                let mut releases: Vec<_> = country
+                    .release_dates
+                    .into_iter()
+                    .filter_map(|release| {
+                        Some(Release {
+                            release_type: ReleaseType::from_number(release.r#type)?,
+                            // Release dates come with a time, e.g. `2019-10-04T00:00:00.000Z`.
+                            date: release.release_date.get(..10).and_then(|d| d.parse().ok()),
+                            certification: release.certification,
+                            language: release.iso_639_1,
+                            note: release.note,
+                        })
+                    })
+                    .collect();
+                releases.sort_by_key(|release| (release.date.is_none(), release.date));
+                Some((region, releases))
+            })
+            .collect();
+        Self { results }
+    }
+}
+
+impl ReleaseDates {
+    /// The releases in `region`, oldest first.
+    pub fn region(&self, region: Region) -> &[Release] {
+        self.results
+            .get(&region)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// The first release of the given type in `region`.
+    pub fn release(&self, region: Region, release_type: ReleaseType) -> Option<&Release> {
+        self.region(region)
+            .iter()
+            .find(|release| release.release_type == release_type)
+    }
+
+    pub fn digital_release(&self, region: Region) -> Option<Date> {
+        self.release(region, ReleaseType::Digital)?.date
+    }
+}
+
+/// Titles with a certification per country.
+pub trait AgeRated {
+    const MEDIA_TYPE: MediaType;
+
+    fn certification(&self, region: Region) -> Option<&str>;
+
+    /// Whether a viewer of `age` may watch this title in `region`. Returns
+    /// `None`, if the title is not certified there or the certification is
+    /// unknown or unrated.
+    fn allowed_for_age(
+        &self,
+        client: &mut TmdbClient,
+        region: Region,
+        age: u8,
+    ) -> Result<Option<bool>, Error> {
+        let Some(certification) = self.certification(region) else {
+            return Ok(None);
+        };
+        let certifications = client.certifications(Self::MEDIA_TYPE)?;
+        Ok(certifications
+            .minimum_age(region, certification)
+            .map(|minimum| age >= minimum))
+    }
+}
+
+impl AgeRated for ReleaseDates {
+    const MEDIA_TYPE: MediaType = MediaType::Movie;
+
+    /// Prefers the certification of the theatrical release, then any other
+    /// certified release.
+    fn certification(&self, region: Region) -> Option<&str> {
+        let certified = |release: &&Release| !release.certification.is_empty();
+        let releases = self.region(region);
+        releases
+            .iter()
+            .filter(certified)
+            .find(|release| release.release_type == ReleaseType::Theatrical)
+            .or_else(|| releases.iter().find(certified))
+            .map(|release| release.certification.as_str())
+    }
+}
+
+/// The certifications of a tv series, per country.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ContentRatings {
+    pub results: BTreeMap<Region, String>,
+}
+
+impl From<TvSeriesContentRatingsResponse200> for ContentRatings {
+    fn from(value: TvSeriesContentRatingsResponse200) -> Self {
+        Self {
+            results: value
+                .results
+                .into_iter()
+                .filter(|rating| !rating.rating.is_empty())
+                .filter_map(|rating| Some((rating.iso_3166_1.parse().ok()?, rating.rating)))
+                .collect(),
+        }
+    }
+}
+
+impl AgeRated for ContentRatings {
+    const MEDIA_TYPE: MediaType = MediaType::Tv;
+
+    fn certification(&self, region: Region) -> Option<&str> {
+        self.results.get(&region).map(String::as_str)
+    }
+}
+
+#[test]
+fn certification_and_release_per_region() {
+    let release_dates: MovieReleaseDatesResponse200 = serde_json::from_str(
+        r#"{"id": 550, "results": [{"iso_3166_1": "DE", "release_dates": [
+            {"certification": "", "release_date": "2000-06-01T00:00:00.000Z", "type": 4},
+            {"certification": "18", "release_date": "1999-11-11T00:00:00.000Z", "type": 3}
+        ]}]}"#,
+    )
+    .unwrap();
+    let release_dates = ReleaseDates::from(release_dates);
+    let de: Region = "DE".parse().unwrap();
+    assert_eq!(release_dates.certification(de), Some("18"));
+    assert_eq!(
+        release_dates.digital_release(de),
+        Some(Date::new(2000, 6, 1))
+    );
+    assert_eq!(
+        release_dates.region(de)[0].release_type,
+        ReleaseType::Theatrical
+    );
+
+    let certifications: Certifications = serde_json::from_str(
+        r#"{"certifications": {"US": [
+            {"certification": "R", "order": 4},
+            {"certification": "G", "meaning": "All ages admitted.", "order": 1},
+            {"certification": "PG-13", "order": 3}
+        ]}}"#,
+    )
+    .unwrap();
+    let us: Region = "US".parse().unwrap();
+    assert_eq!(certifications.minimum_age(us, "PG-13"), Some(13));
+    assert_eq!(certifications.minimum_age(us, "G"), Some(0));
+    // Without a meaning and nothing more restrictive, R has no known age.
+    assert_eq!(certifications.minimum_age(us, "R"), None);
+    assert_eq!(
+        certifications.compare(us, "R", "G"),
+        Some(Ordering::Greater)
+    );
+}
+
+#[test]
+fn every_rated_certification_has_an_age() {
+    let certifications: Certifications = serde_json::from_str(
+        r#"{"certifications": {"US": [
+            {"certification": "NR", "meaning": "No rating information.", "order": 0},
+            {"certification": "G", "meaning": "All ages admitted.", "order": 1},
+            {"certification": "PG", "meaning": "Some material may not be suitable for children.", "order": 2},
+            {"certification": "PG-13", "meaning": "Some material may be inappropriate for children under 13.", "order": 3},
+            {"certification": "R", "meaning": "Under 17 requires accompanying parent or adult guardian 21 or older.", "order": 4},
+            {"certification": "NC-17", "meaning": "No one 17 and under admitted.", "order": 5}
+        ]}}"#,
+    )
+    .unwrap();
+    let us: Region = "US".parse().unwrap();
+    let ages: Vec<_> = ["NR", "G", "PG", "PG-13", "R", "NC-17"]
+        .into_iter()
+        .map(|certification| certifications.minimum_age(us, certification))
+        .collect();
+    assert_eq!(
+        ages,
+        [None, Some(0), Some(13), Some(13), Some(17), Some(18)]
+    );
+
+    let tv: Certifications = serde_json::from_str(
+        r#"{"certifications": {"US": [
+            {"certification": "NR", "meaning": "No rating information.", "order": 0},
+            {"certification": "TV-Y", "meaning": "This program is designed to be appropriate for all children.", "order": 1},
+            {"certification": "TV-Y7", "meaning": "This program is designed for children age 7 and above.", "order": 2},
+            {"certification": "TV-G", "meaning": "Most parents would find this program suitable for all ages.", "order": 3},
+            {"certification": "TV-PG", "meaning": "This program contains material that parents may find unsuitable for younger children.", "order": 4},
+            {"certification": "TV-14", "meaning": "This program may be unsuitable for children under 14 years of age.", "order": 5},
+            {"certification": "TV-MA", "meaning": "This program is intended to be viewed by adults and may be unsuitable for children under 17.", "order": 6}
+        ]}}"#,
+    )
+    .unwrap();
+    let ages: Vec<_> = ["NR", "TV-Y", "TV-Y7", "TV-G", "TV-PG", "TV-14", "TV-MA"]
+        .into_iter()
+        .map(|certification| tv.minimum_age(us, certification))
+        .collect();
+    assert_eq!(
+        ages,
+        [
+            None,
+            Some(7),
+            Some(7),
+            Some(0),
+            Some(14),
+            Some(14),
+            Some(17)
+        ]
+    );
+
+    let de: Certifications = serde_json::from_str(
+        r#"{"certifications": {"DE": [
+            {"certification": "0", "meaning": "No age restriction.", "order": 1},
+            {"certification": "12", "meaning": "Children 12 or older admitted, children between 6 and 11 only when accompanied by parent or a legal guardian.", "order": 3},
+            {"certification": "18", "meaning": "No youth admitted, only adults.", "order": 5}
+        ]}}"#,
+    )
+    .unwrap();
+    let de_region: Region = "DE".parse().unwrap();
+    assert_eq!(de.minimum_age(de_region, "0"), Some(0));
+    assert_eq!(de.minimum_age(de_region, "12"), Some(12));
+    assert_eq!(de.minimum_age(de_region, "18"), Some(18));
+}
@@ -0,0 +1,369 @@
+//! Keeps a local copy of tmdb data up to date, by only refetching what
+//! changed in a date window.
+
+use std::{collections::BTreeSet, fmt::Display};
+
+use crate::{client::TmdbClient, date::Date, error::Error, page::Page};
+
+/// The lists of changed ids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    Movie,
+    Tv,
+    Person,
+}
+
+/// Everything with a change history. Seasons and episodes are identified by
+/// their own id, not by their number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeTarget {
+    Movie(u64),
+    Tv(u64),
+    Person(u64),
+    Season(u64),
+    Episode(u64),
+}
+
+impl ChangeTarget {
+    fn endpoint(self) -> (&'static str, String) {
+        match self {
+            ChangeTarget::Movie(id) => (
+                "https://api.themoviedb.org/3/movie/{movie_id}/changes",
+                format!("/3/movie/{id}/changes"),
+            ),
+            ChangeTarget::Tv(id) => (
+                "https://api.themoviedb.org/3/tv/{series_id}/changes",
+                format!("/3/tv/{id}/changes"),
+            ),
+            ChangeTarget::Person(id) => (
+                "https://api.themoviedb.org/3/person/{person_id}/changes",
+                format!("/3/person/{id}/changes"),
+            ),
+            ChangeTarget::Season(id) => (
+                "https://api.themoviedb.org/3/tv/season/{season_id}/changes",
+                format!("/3/tv/season/{id}/changes"),
+            ),
+            ChangeTarget::Episode(id) => (
+                "https://api.themoviedb.org/3/tv/episode/{episode_id}/changes",
+                format!("/3/tv/episode/{id}/changes"),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChangeAction {
+    Added,
+    Updated,
+    Deleted,
+    Created,
+    Destroyed,
+    Other(String),
+}
+
+impl From<String> for ChangeAction {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "added" => ChangeAction::Added,
+            "updated" => ChangeAction::Updated,
+            "deleted" => ChangeAction::Deleted,
+            "created" => ChangeAction::Created,
+            "destroyed" => ChangeAction::Destroyed,
+            _ => ChangeAction::Other(value),
+        }
+    }
+}
+
+impl Display for ChangeAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangeAction::Added => write!(f, "added"),
+            ChangeAction::Updated => write!(f, "updated"),
+            ChangeAction::Deleted => write!(f, "deleted"),
+            ChangeAction::Created => write!(f, "created"),
+            ChangeAction::Destroyed => write!(f, "destroyed"),
+            ChangeAction::Other(action) => write!(f, "{action}"),
+        }
+    }
+}
+
+/// A single edit of a field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    /// The changed field, e.g. `title`, `overview` or `images`.
+    pub key: String,
+    pub action: ChangeAction,
+    /// The id of the edit.
+    pub id: String,
+    /// E.g. `2024-03-01 12:34:56 UTC`.
+    pub time: String,
+    /// Empty, if the field is not translated.
+    pub language: String,
+    /// Empty, if the field is not per region.
+    pub region: String,
+    /// The shape depends on the key, `None` for deletions.
+    pub value: Option<serde_json::Value>,
+    /// The value before an update or deletion.
+    pub original_value: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+struct ChangeItem {
+    id: String,
+    action: String,
+    time: String,
+    iso_639_1: String,
+    iso_3166_1: String,
+    value: Option<serde_json::Value>,
+    original_value: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+struct ChangeGroup {
+    key: String,
+    items: Vec<ChangeItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+struct ChangesResponse {
+    changes: Vec<ChangeGroup>,
+}
+
+/// The change history of a movie, tv series, season, episode or person.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Changes {
+    pub changes: Vec<Change>,
+}
+
+impl From<ChangesResponse> for Changes {
+    fn from(value: ChangesResponse) -> Self {
+        Self {
+            changes: value
+                .changes
+                .into_iter()
+                .flat_map(|group| {
+                    let key = group.key;
+                    group.items.into_iter().map(move |item| Change {
+                        key: key.clone(),
+                        action: item.action.into(),
+                        id: item.id,
+                        time: item.time,
+                        language: item.iso_639_1,
+                        region: item.iso_3166_1,
+                        value: item.value,
+                        original_value: item.original_value,
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Changes {
+    /// The changed fields, to decide which sections to refetch.
+    pub fn keys(&self) -> BTreeSet<&str> {
+        self.changes
+            .iter()
+            .map(|change| change.key.as_str())
+            .collect()
+    }
+
+    pub fn key<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a Change> {
+        self.changes.iter().filter(move |change| change.key == key)
+    }
+}
+
+/// Loads the change history of a single entry.
+pub struct ChangeHistoryBuilder<'a> {
+    client: &'a TmdbClient,
+    target: ChangeTarget,
+    start_date: Option<Date>,
+    end_date: Option<Date>,
+}
+
+impl<'a> ChangeHistoryBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, target: ChangeTarget) -> Self {
+        Self {
+            client,
+            target,
+            start_date: None,
+            end_date: None,
+        }
+    }
+
+    /// tmdb returns at most 14 days, the last day by default.
+    pub fn with_start_date(mut self, date: Date) -> Self {
+        self.start_date = Some(date);
+        self
+    }
+
+    pub fn with_end_date(mut self, date: Date) -> Self {
+        self.end_date = Some(date);
+        self
+    }
+
+    pub fn load(self) -> Result<Changes, Error> {
+        let (source, path) = self.target.endpoint();
+        let query = window_query(self.start_date, self.end_date);
+        let response: ChangesResponse = self.client.get_json(source, &path, &query)?;
+        Ok(response.into())
+    }
+}
+
+fn window_query(start_date: Option<Date>, end_date: Option<Date>) -> Vec<(&'static str, String)> {
+    let mut query = Vec::new();
+    if let Some(start_date) = start_date {
+        query.push(("start_date", start_date.to_string()));
+    }
+    if let Some(end_date) = end_date {
+        query.push(("end_date", end_date.to_string()));
+    }
+    query
+}
+
+/// An entry, that changed in the requested window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct ChangedId {
+    pub id: u64,
+    /// tmdb sends `null` for some entries.
+    #[serde(deserialize_with = "null_is_false")]
+    pub adult: bool,
+}
+
+fn null_is_false<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    Ok(<Option<bool> as serde::Deserialize>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Lists all movies, tv series or people, that changed in a date window.
+pub struct ChangedIdsBuilder<'a> {
+    client: &'a TmdbClient,
+    kind: ChangeKind,
+    start_date: Option<Date>,
+    end_date: Option<Date>,
+}
+
+impl<'a> ChangedIdsBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, kind: ChangeKind) -> Self {
+        Self {
+            client,
+            kind,
+            start_date: None,
+            end_date: None,
+        }
+    }
+
+    /// tmdb returns at most 14 days, the last day by default.
+    pub fn with_start_date(mut self, date: Date) -> Self {
+        self.start_date = Some(date);
+        self
+    }
+
+    pub fn with_end_date(mut self, date: Date) -> Self {
+        self.end_date = Some(date);
+        self
+    }
+
+    fn page(&self, page: i64) -> Result<Page<ChangedId>, Error> {
+        let (source, path) = match self.kind {
+            ChangeKind::Movie => (
+                "https://api.themoviedb.org/3/movie/changes",
+                "/3/movie/changes",
+            ),
+            ChangeKind::Tv => ("https://api.themoviedb.org/3/tv/changes", "/3/tv/changes"),
+            ChangeKind::Person => (
+                "https://api.themoviedb.org/3/person/changes",
+                "/3/person/changes",
+            ),
+        };
+        let mut query = window_query(self.start_date, self.end_date);
+        query.push(("page", page.to_string()));
+        self.client.get_json(source, path, &query)
+    }
+
+    /// Walks all pages and returns every changed id once.
+    pub fn load(self) -> Result<Vec<ChangedId>, Error> {
+        let first = self.page(1)?;
+        let mut ids = first.results;
+        for page in 2..=first.total_pages {
+            ids.extend(self.page(page)?.results);
+        }
+        let mut seen = BTreeSet::new();
+        ids.retain(|changed| seen.insert(changed.id));
+        Ok(ids)
+    }
+
+    /// Loads the changed ids together with their change history in the same
+    /// window. Makes one request per changed id.
+    pub fn load_changes(self) -> Result<Vec<(ChangedId, Changes)>, Error> {
+        let (client, kind, start_date, end_date) =
+            (self.client, self.kind, self.start_date, self.end_date);
+        self.load()?
+            .into_iter()
+            .map(|changed| {
+                let target = match kind {
+                    ChangeKind::Movie => ChangeTarget::Movie(changed.id),
+                    ChangeKind::Tv => ChangeTarget::Tv(changed.id),
+                    ChangeKind::Person => ChangeTarget::Person(changed.id),
+                };
+                let mut history = ChangeHistoryBuilder::new(client, target);
+                history.start_date = start_date;
+                history.end_date = end_date;
+                Ok((changed, history.load()?))
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn changes_are_flattened() {
+    let response: ChangesResponse = serde_json::from_str(
+        r#"{"changes": [
+            {"key": "overview", "items": [
+                {"id": "a", "action": "updated", "time": "2024-03-01 12:00:00 UTC", "iso_639_1": "de", "value": "Neu", "original_value": "Alt"}
+            ]},
+            {"key": "images", "items": [
+                {"id": "b", "action": "added", "time": "2024-03-01 13:00:00 UTC", "value": {"poster": {"file_path": "/p.jpg"}}},
+                {"id": "c", "action": "deleted", "time": "2024-03-01 13:00:00 UTC", "original_value": {"poster": {"file_path": "/o.jpg"}}}
+            ]}
+        ]}"#,
+    )
+    .unwrap();
+    let changes = Changes::from(response);
+    assert_eq!(
+        changes.keys().into_iter().collect::<Vec<_>>(),
+        ["images", "overview"]
+    );
+    let overview = changes.key("overview").next().unwrap();
+    assert_eq!(overview.action, ChangeAction::Updated);
+    assert_eq!(overview.language, "de");
+    assert_eq!(overview.original_value, Some("Alt".into()));
+    let deleted = changes.key("images").nth(1).unwrap();
+    assert_eq!(deleted.action, ChangeAction::Deleted);
+    assert_eq!(deleted.value, None);
+}
+
+#[test]
+fn changed_ids_tolerate_null_adult() {
+    let page: Page<ChangedId> = serde_json::from_str(
+        r#"{"results": [{"id": 550, "adult": false}, {"id": 551, "adult": null}], "page": 1, "total_pages": 3}"#,
+    )
+    .unwrap();
+    assert_eq!(page.total_pages, 3);
+    assert_eq!(
+        page.results,
+        [
+            ChangedId {
+                id: 550,
+                adult: false
+            },
+            ChangedId {
+                id: 551,
+                adult: false
+            }
+        ]
+    );
+}
@@ -0,0 +1,300 @@
+use std::{borrow::Cow, marker::PhantomData, ops::RangeInclusive};
+
+use tmdb_easy_raw::{
+    parameter_types::{
+        MovieNowPlayingListParameter, MoviePopularListParameter, MovieTopRatedListParameter,
+        MovieUpcomingListParameter, TvSeriesAiringTodayListParameter,
+        TvSeriesOnTheAirListParameter, TvSeriesPopularListParameter, TvSeriesTopRatedListParameter,
+    },
+    types::{
+        MovieNowPlayingListResponse200, MovieNowPlayingListResponse200Results,
+        MoviePopularListResponse200, MoviePopularListResponse200Results,
+        MovieTopRatedListResponse200, MovieTopRatedListResponse200Results,
+        MovieUpcomingListResponse200, MovieUpcomingListResponse200Results,
+        TvSeriesAiringTodayListResponse200, TvSeriesAiringTodayListResponse200Results,
+        TvSeriesOnTheAirListResponse200, TvSeriesOnTheAirListResponse200Results,
+        TvSeriesPopularListResponse200, TvSeriesPopularListResponse200Results,
+        TvSeriesTopRatedListResponse200, TvSeriesTopRatedListResponse200Results,
+    },
+};
+
+use crate::{
+    client::TmdbClient,
+    date::Date,
+    error::Error,
+    localization::Locale,
+    page::{PagedBuilder, PagedResponse, ResultPage, result_page},
+    region::Region,
+    timezone::Timezone,
+};
+
+/// The parameters shared by all charts. Not every chart uses every parameter.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ChartParameters<'a> {
+    pub(crate) language: Option<Cow<'a, str>>,
+    pub(crate) page: Option<i64>,
+    pub(crate) region: Option<Cow<'a, str>>,
+    pub(crate) timezone: Option<Cow<'a, str>>,
+}
+
+/// One of the curated lists, like popular movies or tv series airing today.
+/// Implemented by the raw response types.
+pub trait Chart: ResultPage + Sized {
+    fn fetch(builder: &ChartBuilder<Self>) -> Result<Self, Error>;
+}
+
+/// Charts, that can be restricted to a region.
+pub trait RegionalChart: Chart {}
+
+/// Charts, that depend on the current day in a timezone.
+pub trait AiringChart: Chart {}
+
+/// Charts, that only contain movies released in a certain window of dates.
+pub trait ReleaseWindowChart: Chart {
+    fn minimum_date(&self) -> &str;
+    fn maximum_date(&self) -> &str;
+}
+
+macro_rules! chart {
+    ($response:ident, $item:ident, $function:ident, $parameter:ident { $($field:ident),* }) => {
+        impl Chart for $response {
+            fn fetch(builder: &ChartBuilder<Self>) -> Result<Self, Error> {
+                let parameters = builder.parameters.clone();
+                Ok(tmdb_easy_raw::parametrized_functions::$function(
+                    &builder.client.client,
+                    &builder.client.api_key,
+                    $parameter {
+                        $($field: parameters.$field),*
+                    },
+                )?)
+            }
+        }
+
+        result_page!($response, $item);
+    };
+}
+
+chart!(
+    MoviePopularListResponse200,
+    MoviePopularListResponse200Results,
+    movie_popular_list_with_parameter,
+    MoviePopularListParameter {
+        language,
+        page,
+        region
+    }
+);
+chart!(
+    MovieTopRatedListResponse200,
+    MovieTopRatedListResponse200Results,
+    movie_top_rated_list_with_parameter,
+    MovieTopRatedListParameter {
+        language,
+        page,
+        region
+    }
+);
+chart!(
+    MovieUpcomingListResponse200,
+    MovieUpcomingListResponse200Results,
+    movie_upcoming_list_with_parameter,
+    MovieUpcomingListParameter {
+        language,
+        page,
+        region
+    }
+);
+chart!(
+    MovieNowPlayingListResponse200,
+    MovieNowPlayingListResponse200Results,
+    movie_now_playing_list_with_parameter,
+    MovieNowPlayingListParameter {
+        language,
+        page,
+        region
+    }
+);
+chart!(
+    TvSeriesAiringTodayListResponse200,
+    TvSeriesAiringTodayListResponse200Results,
+    tv_series_airing_today_list_with_parameter,
+    TvSeriesAiringTodayListParameter {
+        language,
+        page,
+        timezone
+    }
+);
+chart!(
+    TvSeriesOnTheAirListResponse200,
+    TvSeriesOnTheAirListResponse200Results,
+    tv_series_on_the_air_list_with_parameter,
+    TvSeriesOnTheAirListParameter {
+        language,
+        page,
+        timezone
+    }
+);
+chart!(
+    TvSeriesPopularListResponse200,
+    TvSeriesPopularListResponse200Results,
+    tv_series_popular_list_with_parameter,
+    TvSeriesPopularListParameter { language, page }
+);
+chart!(
+    TvSeriesTopRatedListResponse200,
+    TvSeriesTopRatedListResponse200Results,
+    tv_series_top_rated_list_with_parameter,
+    TvSeriesTopRatedListParameter { language, page }
+);
+
+impl RegionalChart for MoviePopularListResponse200 {}
+impl RegionalChart for MovieTopRatedListResponse200 {}
+impl RegionalChart for MovieUpcomingListResponse200 {}
+impl RegionalChart for MovieNowPlayingListResponse200 {}
+impl AiringChart for TvSeriesAiringTodayListResponse200 {}
+impl AiringChart for TvSeriesOnTheAirListResponse200 {}
+
+impl ReleaseWindowChart for MovieUpcomingListResponse200 {
+    fn minimum_date(&self) -> &str {
+        &self.dates.minimum
+    }
+
+    fn maximum_date(&self) -> &str {
+        &self.dates.maximum
+    }
+}
+
+impl ReleaseWindowChart for MovieNowPlayingListResponse200 {
+    fn minimum_date(&self) -> &str {
+        &self.dates.minimum
+    }
+
+    fn maximum_date(&self) -> &str {
+        &self.dates.maximum
+    }
+}
+
+pub type ChartResponse<'a, C> = PagedResponse<ChartBuilder<'a, C>>;
+
+impl<C: ReleaseWindowChart> ChartResponse<'_, C> {
+    /// The release dates covered by this chart. Returns `None` if tmdb sent
+    /// dates, that could not be parsed.
+    pub fn dates(&self) -> Option<RangeInclusive<Date>> {
+        let minimum = self.page.minimum_date().parse().ok()?;
+        let maximum = self.page.maximum_date().parse().ok()?;
+        Some(minimum..=maximum)
+    }
+}
+
+pub struct ChartBuilder<'a, C> {
+    client: &'a TmdbClient,
+    parameters: ChartParameters<'a>,
+    chart: PhantomData<C>,
+}
+
+impl<'a, C: Chart> ChartBuilder<'a, C> {
+    /// Uses the default language and region of the client, if they are set.
+    pub fn new(client: &'a TmdbClient) -> Self {
+        Self {
+            client,
+            parameters: ChartParameters {
+                language: client.language.clone(),
+                region: client.region.clone(),
+                ..Default::default()
+            },
+            chart: PhantomData,
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.parameters.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_page(mut self, page: i64) -> Self {
+        self.parameters.page = Some(page);
+        self
+    }
+
+    pub fn search(self) -> Result<ChartResponse<'a, C>, Error> {
+        PagedResponse::load(self)
+    }
+}
+
+impl<C: Chart> PagedBuilder for ChartBuilder<'_, C> {
+    type Page = C;
+
+    fn fetch_page(&self) -> Result<Self::Page, Error> {
+        C::fetch(self)
+    }
+
+    fn set_page(&mut self, page: i64) {
+        self.parameters.page = Some(page);
+    }
+}
+
+impl<'a, C: RegionalChart> ChartBuilder<'a, C> {
+    pub fn with_region(mut self, region: Region) -> Self {
+        self.parameters.region = Some(region.to_string().into());
+        self
+    }
+}
+
+impl<'a, C: AiringChart> ChartBuilder<'a, C> {
+    /// Timezone, that decides which day it is, e.g. `America/New_York`.
+    pub fn with_timezone(mut self, timezone: Timezone) -> Self {
+        self.parameters.timezone = Some(timezone.to_string().into());
+        self
+    }
+}
+
+#[test]
+fn charts_with_fractional_ratings() {
+    let upcoming: MovieUpcomingListResponse200 = serde_json::from_str(
+        r#"{"page": 1, "total_pages": 2, "total_results": 21,
+            "dates": {"minimum": "2024-05-01", "maximum": "2024-05-22"},
+            "results": [{"id": 653346, "title": "Kingdom of the Planet of the Apes", "vote_average": 7.5, "vote_count": 120}]}"#,
+    )
+    .unwrap();
+    assert_eq!(upcoming.total_pages(), 2);
+    assert_eq!(upcoming.results()[0].vote_average, 7.5);
+
+    let series = r#"{"page": 1, "total_pages": 1, "total_results": 1,
+        "results": [{"id": 1399, "name": "Game of Thrones", "vote_average": 7.5, "origin_country": ["US"]}]}"#;
+    let popular: TvSeriesPopularListResponse200 = serde_json::from_str(series).unwrap();
+    assert_eq!(popular.results()[0].vote_average, 7.5);
+    let airing_today: TvSeriesAiringTodayListResponse200 = serde_json::from_str(series).unwrap();
+    assert_eq!(airing_today.results()[0].name, "Game of Thrones");
+    assert_eq!(airing_today.results()[0].vote_average, 7.5);
+    let on_the_air: TvSeriesOnTheAirListResponse200 = serde_json::from_str(series).unwrap();
+    assert_eq!(on_the_air.results()[0].vote_average, 7.5);
+}
+
+#[test]
+fn chart_release_window() {
+    let client = TmdbClient::new("offline");
+    let page: MovieNowPlayingListResponse200 = serde_json::from_str(
+        r#"{"page": 1, "total_pages": 1, "total_results": 0, "results": [],
+            "dates": {"minimum": "2024-04-10", "maximum": "2024-05-22"}}"#,
+    )
+    .unwrap();
+    let response = ChartResponse {
+        builder: ChartBuilder::new(&client),
+        page,
+    };
+    assert_eq!(
+        response.dates(),
+        Some(Date::new(2024, 4, 10)..=Date::new(2024, 5, 22))
+    );
+
+    let page: MovieUpcomingListResponse200 = serde_json::from_str(
+        r#"{"page": 1, "total_pages": 1, "total_results": 0, "results": [],
+            "dates": {"minimum": "2024-05-23", "maximum": ""}}"#,
+    )
+    .unwrap();
+    let response = ChartResponse {
+        builder: ChartBuilder::new(&client),
+        page,
+    };
+    assert_eq!(response.dates(), None);
+}
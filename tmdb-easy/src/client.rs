@@ -1,17 +1,77 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashMap};
 
-use tmdb_easy_raw::types::{ConfigurationDetailsResponse200, MovieDetailsResponse200};
+use reqwest::StatusCode;
+use tmdb_easy_raw::{
+    ErrorContext,
+    types::{
+        ConfigurationDetailsResponse200, MovieNowPlayingListResponse200,
+        MoviePopularListResponse200, MovieTopRatedListResponse200, MovieUpcomingListResponse200,
+        TrendingMoviesResponse200Results, TrendingPeopleResponse200Results,
+        TrendingTvResponse200Results, TvSeriesAiringTodayListResponse200,
+        TvSeriesOnTheAirListResponse200, TvSeriesPopularListResponse200,
+        TvSeriesTopRatedListResponse200,
+    },
+};
 
 use crate::{
+    MediaType,
+    account::AccountBuilder,
+    authentication::{GuestSession, RequestToken, Session},
+    certification::Certifications,
+    changes::{ChangeHistoryBuilder, ChangeKind, ChangeTarget, ChangedIdsBuilder},
+    chart::ChartBuilder,
+    collection::CollectionBuilder,
+    company::{Company, Network},
+    configuration::{DepartmentJobs, ReferenceData},
+    discover::{movie::DiscoverMovieBuilder, tv::DiscoverTvBuilder},
+    episode_group::EpisodeGroup,
     error::{Error, ErrorKind},
+    external_ids::{ExternalSource, FindBuilder},
+    genre::{GenreKind, GenreList},
+    id::{CompanyId, KeywordId, NetworkId},
+    keyword::Keyword,
+    localization::{FallbackChain, Locale, Localizable, Localized, Translations},
+    movie::MovieBuilder,
+    person::PersonBuilder,
+    region::Region,
+    review::ReviewDetails,
     search::movie::SearchMovieBuilder,
+    search::person::SearchPersonBuilder,
     search::tv_show::SearchTvBuilder,
+    trending::{TimeWindow, TrendingBuilder, TrendingItem},
+    tv::TvSeriesBuilder,
+    watch_provider::{WatchProviderListBuilder, WatchRegion, available_regions},
 };
 
+/// The kinds of images tmdb serves, each has its own list of sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ImageKind {
+    Poster,
+    Profile,
+    Backdrop,
+    Logo,
+}
+
+impl ImageKind {
+    fn source(self) -> &'static str {
+        match self {
+            ImageKind::Poster => "tmdb api poster_path",
+            ImageKind::Profile => "tmdb api profile_path",
+            ImageKind::Backdrop => "tmdb api backdrop_path",
+            ImageKind::Logo => "tmdb api logo_path",
+        }
+    }
+}
+
 pub struct TmdbClient {
     pub(crate) client: reqwest::blocking::Client,
     pub(crate) api_key: Cow<'static, str>,
     pub(crate) configuration: Option<tmdb_easy_raw::types::ConfigurationDetailsResponse200>,
+    pub(crate) reference_data: Option<ReferenceData>,
+    pub(crate) language: Option<Cow<'static, str>>,
+    pub(crate) region: Option<Cow<'static, str>>,
+    pub(crate) genres: HashMap<(GenreKind, Option<String>), GenreList>,
+    pub(crate) certifications: HashMap<MediaType, Certifications>,
 }
 
 impl TmdbClient {
@@ -20,9 +80,89 @@ impl TmdbClient {
             client: reqwest::blocking::Client::new(),
             api_key: api_key.into(),
             configuration: None,
+            reference_data: None,
+            language: None,
+            region: None,
+            genres: HashMap::new(),
+            certifications: HashMap::new(),
         }
     }
 
+    /// Creates a client and checks the api key with tmdb, so a wrong key is
+    /// reported before the first real request.
+    pub fn validated(api_key: impl Into<Cow<'static, str>>) -> Result<Self, Error> {
+        let client = Self::new(api_key);
+        client.validate_key()?;
+        Ok(client)
+    }
+
+    /// Fails with [`ErrorKind::AuthenticationError`], if tmdb does not accept
+    /// the api key.
+    pub fn validate_key(&self) -> Result<(), Error> {
+        let _: StatusResponse = self.send_json(
+            reqwest::Method::GET,
+            "https://api.themoviedb.org/3/authentication",
+            "/3/authentication",
+            &[],
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Sets the language used by all requests, that are not given one
+    /// explicitly. For example `de-DE`.
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    /// Sets the region used by the movie charts, if they are not given one
+    /// explicitly. For example `DE`.
+    pub fn with_region(mut self, region: Region) -> Self {
+        self.region = Some(region.to_string().into());
+        self
+    }
+
+    /// Starts the login of a user. Let the user approve the token on
+    /// [`RequestToken::approval_url`] or with
+    /// [`RequestToken::validate_with_login`], then call
+    /// [`Self::create_session`].
+    pub fn request_token(&self) -> Result<RequestToken, Error> {
+        RequestToken::fetch(self)
+    }
+
+    /// Fails, if the user did not approve the token.
+    pub fn create_session(&self, token: &RequestToken) -> Result<Session, Error> {
+        Session::create(self, token)
+    }
+
+    /// Logs a user in with their username and password.
+    pub fn login(&self, username: &str, password: &str) -> Result<Session, Error> {
+        let token = self
+            .request_token()?
+            .validate_with_login(self, username, password)?;
+        self.create_session(&token)
+    }
+
+    /// Creates a session from an access token of the version 4 api.
+    pub fn session_from_v4_token(&self, access_token: &str) -> Result<Session, Error> {
+        Session::from_v4_token(self, access_token)
+    }
+
+    /// Restores a session from a previously stored [`Session::id`].
+    pub fn session(&self, id: impl Into<String>) -> Result<Session, Error> {
+        Session::restore(self, id)
+    }
+
+    pub fn guest_session(&self) -> Result<GuestSession, Error> {
+        GuestSession::fetch(self)
+    }
+
+    /// The favorites, watchlist, ratings and lists of the user of `session`.
+    pub fn account<'a>(&'a self, session: &'a Session) -> AccountBuilder<'a> {
+        AccountBuilder::new(self, session)
+    }
+
     pub fn search_for_movie<'a, 'b: 'a>(
         &'a self,
         name: impl Into<Cow<'b, str>>,
@@ -37,6 +177,168 @@ impl TmdbClient {
         SearchTvBuilder::new(self, name.into())
     }
 
+    pub fn discover_movies(&self) -> DiscoverMovieBuilder<'_> {
+        DiscoverMovieBuilder::new(self)
+    }
+
+    pub fn discover_tv(&self) -> DiscoverTvBuilder<'_> {
+        DiscoverTvBuilder::new(self)
+    }
+
+    /// Trending movies, tv series and people mixed together.
+    pub fn trending_all(&self, time_window: TimeWindow) -> TrendingBuilder<'_, TrendingItem> {
+        TrendingBuilder::new(self, time_window)
+    }
+
+    pub fn trending_movies(
+        &self,
+        time_window: TimeWindow,
+    ) -> TrendingBuilder<'_, TrendingMoviesResponse200Results> {
+        TrendingBuilder::new(self, time_window)
+    }
+
+    pub fn trending_tv(
+        &self,
+        time_window: TimeWindow,
+    ) -> TrendingBuilder<'_, TrendingTvResponse200Results> {
+        TrendingBuilder::new(self, time_window)
+    }
+
+    pub fn trending_people(
+        &self,
+        time_window: TimeWindow,
+    ) -> TrendingBuilder<'_, TrendingPeopleResponse200Results> {
+        TrendingBuilder::new(self, time_window)
+    }
+
+    /// Loads the details of the movie with `id`. Use the builder to request
+    /// additional sections like credits or videos.
+    pub fn movie(&self, id: u64) -> MovieBuilder<'_> {
+        MovieBuilder::new(self, id)
+    }
+
+    /// Loads the details of the tv series with `id`. The builder can also
+    /// navigate to its seasons and episodes.
+    pub fn tv(&self, id: u64) -> TvSeriesBuilder<'_> {
+        TvSeriesBuilder::new(self, id)
+    }
+
+    pub fn popular_movies(&self) -> ChartBuilder<'_, MoviePopularListResponse200> {
+        ChartBuilder::new(self)
+    }
+
+    pub fn top_rated_movies(&self) -> ChartBuilder<'_, MovieTopRatedListResponse200> {
+        ChartBuilder::new(self)
+    }
+
+    pub fn upcoming_movies(&self) -> ChartBuilder<'_, MovieUpcomingListResponse200> {
+        ChartBuilder::new(self)
+    }
+
+    pub fn now_playing_movies(&self) -> ChartBuilder<'_, MovieNowPlayingListResponse200> {
+        ChartBuilder::new(self)
+    }
+
+    pub fn popular_tv(&self) -> ChartBuilder<'_, TvSeriesPopularListResponse200> {
+        ChartBuilder::new(self)
+    }
+
+    pub fn top_rated_tv(&self) -> ChartBuilder<'_, TvSeriesTopRatedListResponse200> {
+        ChartBuilder::new(self)
+    }
+
+    pub fn airing_today_tv(&self) -> ChartBuilder<'_, TvSeriesAiringTodayListResponse200> {
+        ChartBuilder::new(self)
+    }
+
+    pub fn on_the_air_tv(&self) -> ChartBuilder<'_, TvSeriesOnTheAirListResponse200> {
+        ChartBuilder::new(self)
+    }
+
+    pub fn search_for_person<'a, 'b: 'a>(
+        &'a self,
+        name: impl Into<Cow<'b, str>>,
+    ) -> SearchPersonBuilder<'a> {
+        SearchPersonBuilder::new(self, name.into())
+    }
+
+    /// Loads the details of the person with `id`. Use the builder to request
+    /// additional sections like their credits.
+    pub fn person(&self, id: u64) -> PersonBuilder<'_> {
+        PersonBuilder::new(self, id)
+    }
+
+    /// Finds movies, tv series, seasons, episodes and people by their id on
+    /// another site, e.g. `client.find("tt0137523", ExternalSource::Imdb)`.
+    pub fn find<'a, 'b: 'a>(
+        &'a self,
+        external_id: impl Into<Cow<'b, str>>,
+        source: ExternalSource,
+    ) -> FindBuilder<'a> {
+        FindBuilder::new(self, external_id.into(), source)
+    }
+
+    /// Lists the movies, tv series or people, that changed recently. Use
+    /// this to find out which local copies are outdated.
+    pub fn changed_ids(&self, kind: ChangeKind) -> ChangedIdsBuilder<'_> {
+        ChangedIdsBuilder::new(self, kind)
+    }
+
+    /// Loads what changed on a single entry recently.
+    pub fn changes(&self, target: ChangeTarget) -> ChangeHistoryBuilder<'_> {
+        ChangeHistoryBuilder::new(self, target)
+    }
+
+    /// Loads an episode group with all its episodes. The ids are listed by
+    /// [`crate::tv::TvLike::episode_groups`].
+    pub fn episode_group(&self, id: &str) -> Result<EpisodeGroup, Error> {
+        EpisodeGroup::fetch(self, id)
+    }
+
+    /// Loads a single review by its id.
+    pub fn review(&self, id: &str) -> Result<ReviewDetails, Error> {
+        ReviewDetails::fetch(self, id)
+    }
+
+    /// Loads a collection of movies, like a franchise.
+    pub fn collection(&self, id: u64) -> CollectionBuilder<'_> {
+        CollectionBuilder::new(self, id)
+    }
+
+    pub fn keyword(&self, id: impl Into<KeywordId>) -> Result<Keyword, Error> {
+        Keyword::fetch(self, id.into())
+    }
+
+    pub fn company(&self, id: impl Into<CompanyId>) -> Result<Company, Error> {
+        Company::fetch(self, id.into())
+    }
+
+    pub fn network(&self, id: impl Into<NetworkId>) -> Result<Network, Error> {
+        Network::fetch(self, id.into())
+    }
+
+    /// Lists the streaming, rental and purchase services tmdb knows for movies
+    /// or tv series.
+    pub fn watch_provider_list(&self, media_type: MediaType) -> WatchProviderListBuilder<'_> {
+        WatchProviderListBuilder::new(self, media_type)
+    }
+
+    /// The regions, that have watch provider data.
+    pub fn watch_provider_regions(&self) -> Result<Vec<WatchRegion>, Error> {
+        available_regions(self)
+    }
+
+    /// All translations of a movie, series, season, episode or collection.
+    pub fn translations(&self, target: Localizable) -> Result<Translations, Error> {
+        target.translations(self)
+    }
+
+    /// The first non-empty title, overview and tagline along `chain`, e.g.
+    /// `de-AT,de-DE,en-US,original`.
+    pub fn localize(&self, target: Localizable, chain: &FallbackChain) -> Result<Localized, Error> {
+        Ok(self.translations(target)?.localize(chain))
+    }
+
     pub fn configuration_details(&mut self) -> Result<&ConfigurationDetailsResponse200, Error> {
         if self.configuration.is_none() {
             self.configuration = Some(tmdb_easy_raw::functions::configuration_details(
@@ -47,49 +349,310 @@ impl TmdbClient {
         Ok(self.configuration.as_ref().unwrap())
     }
 
-    pub(crate) fn resolve_image_path(
+    /// The current countries, languages, primary translations and
+    /// timezones. They are fetched once and cached.
+    /// [`ReferenceData::snapshot`] has them without a request.
+    pub fn reference_data(&mut self) -> Result<&ReferenceData, Error> {
+        if self.reference_data.is_none() {
+            self.reference_data = Some(ReferenceData::fetch(self)?);
+        }
+        Ok(self.reference_data.as_ref().unwrap())
+    }
+
+    /// The jobs of every crew department.
+    pub fn jobs(&self) -> Result<Vec<DepartmentJobs>, Error> {
+        DepartmentJobs::fetch(self)
+    }
+
+    /// The genres of movies or tv series in the default language. They are
+    /// downloaded on first use and cached afterwards.
+    pub fn genres(&mut self, kind: GenreKind) -> Result<&GenreList, Error> {
+        let language = self.language.as_deref().map(str::to_string);
+        self.cached_genres(kind, language)
+    }
+
+    /// Like [`Self::genres`], but in the given language.
+    pub fn genres_in(
         &mut self,
-        poster_path: String,
-    ) -> Result<image::DynamicImage, Error> {
+        kind: GenreKind,
+        language: impl Into<Locale>,
+    ) -> Result<&GenreList, Error> {
+        self.cached_genres(kind, Some(language.into().to_string()))
+    }
+
+    fn cached_genres(
+        &mut self,
+        kind: GenreKind,
+        language: Option<String>,
+    ) -> Result<&GenreList, Error> {
+        let key = (kind, language);
+        if !self.genres.contains_key(&key) {
+            let list = GenreList::fetch(self, kind, key.1.as_deref())?;
+            self.genres.insert(key.clone(), list);
+        }
+        Ok(&self.genres[&key])
+    }
+
+    /// The certifications of movies or tv series in every country. They are
+    /// downloaded on first use and cached afterwards.
+    pub fn certifications(&mut self, media_type: MediaType) -> Result<&Certifications, Error> {
+        if !self.certifications.contains_key(&media_type) {
+            let certifications = Certifications::fetch(self, media_type)?;
+            self.certifications.insert(media_type, certifications);
+        }
+        Ok(&self.certifications[&media_type])
+    }
+
+    /// The url of an image in its largest size.
+    pub(crate) fn image_url(&mut self, image_path: &str, kind: ImageKind) -> Result<String, Error> {
         let configuration = self.configuration_details()?;
-        let bytes = reqwest::blocking::get(format!(
+        let images = &configuration.images;
+        let sizes = match kind {
+            ImageKind::Poster => &images.poster_sizes,
+            ImageKind::Profile => &images.profile_sizes,
+            ImageKind::Backdrop => &images.backdrop_sizes,
+            ImageKind::Logo => &images.logo_sizes,
+        };
+        Ok(format!(
             "{}{}{}",
-            configuration.images.base_url,
-            configuration
-                .images
-                .poster_sizes
-                .last()
-                .expect("at least one size?"),
-            poster_path
+            images.base_url,
+            sizes.last().expect("at least one size?"),
+            image_path
         ))
-        .map_err(|e| Error {
-            source: "tmdb api poster_path",
-            error: ErrorKind::NetworkError(e.status().unwrap_or_default(), e.to_string()),
-        })?
-        .bytes()
-        .map_err(|e| Error {
-            source: "tmdb api poster_path",
-            error: ErrorKind::NetworkError(e.status().unwrap_or_default(), e.to_string()),
-        })?;
+    }
+
+    pub(crate) fn resolve_image_path(
+        &mut self,
+        image_path: String,
+        kind: ImageKind,
+    ) -> Result<image::DynamicImage, Error> {
+        let source = kind.source();
+        let bytes = reqwest::blocking::get(self.image_url(&image_path, kind)?)
+            .map_err(|e| Error {
+                source,
+                error: ErrorKind::NetworkError(e.status().unwrap_or_default(), e.to_string()),
+            })?
+            .bytes()
+            .map_err(|e| Error {
+                source,
+                error: ErrorKind::NetworkError(e.status().unwrap_or_default(), e.to_string()),
+            })?;
         let img = image::ImageReader::new(std::io::Cursor::new(bytes))
             .with_guessed_format()
             .map_err(|_| Error {
-                source: "tmdb api poster_path",
+                source,
                 error: ErrorKind::DecodingError,
             })?
             .decode()
             .map_err(|_| Error {
-                source: "tmdb api poster_path",
+                source,
                 error: ErrorKind::DecodingError,
             })?;
         Ok(img)
     }
 
-    pub(crate) fn movie_details(&self, movie_id: u64) -> Result<MovieDetailsResponse200, Error> {
-        Ok(tmdb_easy_raw::parametrized_functions::movie_details(
-            &self.client,
-            &self.api_key,
-            movie_id as _,
-        )?)
+    /// Requests the details at `path`, together with the sections in
+    /// `append_to_response`.
+    pub(crate) fn get_details<T: serde::de::DeserializeOwned>(
+        &self,
+        source: &'static str,
+        path: &str,
+        language: Option<Cow<'_, str>>,
+        append_to_response: &[&str],
+    ) -> Result<T, Error> {
+        let mut query = Vec::new();
+        if let Some(language) = language {
+            query.push(("language", language.into_owned()));
+        }
+        if !append_to_response.is_empty() {
+            query.push(("append_to_response", append_to_response.join(",")));
+        }
+        self.get_json(source, path, &query)
     }
+
+    /// Requests an endpoint directly, for responses that can not be
+    /// represented by the generated types. An unsuccessful status is an
+    /// error, with the message tmdb gave.
+    pub(crate) fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        source: &'static str,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, Error> {
+        let request = self
+            .client
+            .get(format!("https://api.themoviedb.org{path}"))
+            .query(query);
+        let (status, text, context) = self.execute(source, request)?;
+        read_json(status, text, context)
+    }
+
+    /// Sends a request with an optional json body, for the endpoints, that
+    /// change something. Like with [`Self::get_json`], an unsuccessful status
+    /// is an error.
+    pub(crate) fn send_json<T: serde::de::DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        source: &'static str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<&serde_json::Value>,
+    ) -> Result<T, Error> {
+        let mut request = self
+            .client
+            .request(method, format!("https://api.themoviedb.org{path}"))
+            .query(query);
+        if let Some(body) = body {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.to_string());
+        }
+        let (status, text, context) = self.execute(source, request)?;
+        read_json(status, text, context)
+    }
+
+    /// Adds the api key and returns the status and the response text,
+    /// together with the context for errors.
+    fn execute(
+        &self,
+        source: &'static str,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> Result<(StatusCode, String, ErrorContext), Error> {
+        let request = request
+            .query(&[("api_key", self.api_key.as_ref())])
+            .build()
+            .map_err(|e| tmdb_easy_raw::Error {
+                kind: e.into(),
+                context: ErrorContext {
+                    source,
+                    ..Default::default()
+                },
+            })?;
+        let url = request.url().clone();
+        let response = self
+            .client
+            .execute(request)
+            .map_err(|e| tmdb_easy_raw::Error {
+                kind: e.into(),
+                context: ErrorContext {
+                    source,
+                    url: Some(url.clone()),
+                    ..Default::default()
+                },
+            })?;
+        let status = response.status();
+        let text = response.text().map_err(|e| tmdb_easy_raw::Error {
+            kind: e.into(),
+            context: ErrorContext {
+                source,
+                url: Some(url.clone()),
+                status: Some(status),
+                ..Default::default()
+            },
+        })?;
+        Ok((
+            status,
+            text,
+            ErrorContext {
+                source,
+                url: Some(url),
+                status: Some(status),
+                text: None,
+            },
+        ))
+    }
+}
+
+/// `base` with `segment` appended percent-encoded as a single path segment,
+/// for ids, that are passed in by the user, e.g. `/3/find` and `tt0137523`.
+pub(crate) fn path_with_segment(base: &str, segment: &str) -> String {
+    let mut url = reqwest::Url::parse("https://api.themoviedb.org")
+        .and_then(|url| url.join(base))
+        .expect("the url is valid");
+    url.path_segments_mut()
+        .expect("the url has a path")
+        .push(segment);
+    url.path().to_string()
+}
+
+/// Turns an unsuccessful status into an error, with the message tmdb sent in
+/// the body.
+fn check_status(source: &'static str, status: StatusCode, text: &str) -> Result<(), Error> {
+    if status.is_success() {
+        return Ok(());
+    }
+    let response: StatusResponse = serde_json::from_str(text).unwrap_or_default();
+    Err(Error {
+        source,
+        error: ErrorKind::from_status(status, response.status_code, response.status_message),
+    })
+}
+
+/// Checks the status and parses the response text.
+pub(crate) fn read_json<T: serde::de::DeserializeOwned>(
+    status: StatusCode,
+    text: String,
+    context: ErrorContext,
+) -> Result<T, Error> {
+    check_status(context.source, status, &text)?;
+    parse_json(text, context)
+}
+
+fn parse_json<T: serde::de::DeserializeOwned>(
+    text: String,
+    context: ErrorContext,
+) -> Result<T, Error> {
+    Ok(
+        serde_json::from_str(&text).map_err(|e| tmdb_easy_raw::Error {
+            kind: e.into(),
+            context: ErrorContext {
+                text: Some(text.clone()),
+                ..context
+            },
+        })?,
+    )
+}
+
+/// The body tmdb sends with errors, e.g. `{"status_code": 30, "status_message":
+/// "Invalid username and/or password: You did not provide a valid login."}`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+struct StatusResponse {
+    status_code: i64,
+    status_message: String,
+}
+
+#[test]
+fn error_bodies_are_mapped_by_status() {
+    let source = "https://api.themoviedb.org/3/authentication";
+    assert!(check_status(source, StatusCode::OK, r#"{"success": true}"#).is_ok());
+
+    // The answer validate_key gets for a wrong api key.
+    let error = check_status(
+        source,
+        StatusCode::UNAUTHORIZED,
+        r#"{"status_code": 7, "status_message": "Invalid API key: You must be granted a valid key.", "success": false}"#,
+    )
+    .unwrap_err();
+    assert_eq!(error.source, source);
+    assert!(matches!(error.error, ErrorKind::AuthenticationError));
+
+    let error = check_status(
+        source,
+        StatusCode::UNAUTHORIZED,
+        r#"{"status_code": 30, "status_message": "Invalid username and/or password: You did not provide a valid login.", "success": false}"#,
+    )
+    .unwrap_err();
+    let ErrorKind::RequestRejected(status, message) = error.error else {
+        panic!("expected a rejected request, got {:?}", error.error);
+    };
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert!(message.starts_with("Invalid username and/or password"));
+
+    let error =
+        check_status(source, StatusCode::BAD_GATEWAY, "<html>Bad Gateway</html>").unwrap_err();
+    assert!(matches!(
+        error.error,
+        ErrorKind::RequestRejected(StatusCode::BAD_GATEWAY, ref message) if message.is_empty()
+    ));
 }
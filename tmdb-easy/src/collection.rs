@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+
+use image::DynamicImage;
+use tmdb_easy_raw::{
+    parameter_types::CollectionImagesParameter,
+    types::{
+        CollectionDetailsResponse200, CollectionDetailsResponse200Parts,
+        CollectionImagesResponse200, CollectionTranslationsResponse200,
+    },
+};
+
+use crate::{
+    MediaType,
+    client::{ImageKind, TmdbClient},
+    date::Date,
+    error::Error,
+    impl_movie_or_tv_like,
+    localization::Locale,
+};
+
+impl_movie_or_tv_like!(CollectionDetailsResponse200Parts, MediaType::Movie);
+
+/// The short reference to a collection, that is part of the movie details.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+pub struct CollectionReference {
+    pub id: u64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub poster_path: Option<String>,
+    #[serde(default)]
+    pub backdrop_path: Option<String>,
+}
+
+impl CollectionReference {
+    /// Prepares loading the full collection.
+    pub fn details<'a>(&self, client: &'a TmdbClient) -> CollectionBuilder<'a> {
+        CollectionBuilder::new(client, self.id)
+    }
+}
+
+/// A collection of movies, like a franchise. The parts are sorted by their
+/// release date, parts without a release date come last.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collection {
+    pub details: CollectionDetailsResponse200,
+}
+
+impl From<CollectionDetailsResponse200> for Collection {
+    fn from(mut details: CollectionDetailsResponse200) -> Self {
+        details.parts.sort_by_key(|part| {
+            let date = release_date(part);
+            (date.is_none(), date)
+        });
+        Self { details }
+    }
+}
+
+impl Collection {
+    pub fn id(&self) -> u64 {
+        self.details.id as _
+    }
+
+    pub fn name(&self) -> &str {
+        &self.details.name
+    }
+
+    /// All parts, ordered by release date.
+    pub fn parts(&self) -> &[CollectionDetailsResponse200Parts] {
+        &self.details.parts
+    }
+
+    /// The parts released on or before `today`.
+    pub fn released(
+        &self,
+        today: Date,
+    ) -> impl Iterator<Item = &CollectionDetailsResponse200Parts> {
+        self.parts()
+            .iter()
+            .filter(move |part| release_date(part).is_some_and(|date| date <= today))
+    }
+
+    /// The parts released after `today`, including announced parts without a
+    /// release date.
+    pub fn upcoming(
+        &self,
+        today: Date,
+    ) -> impl Iterator<Item = &CollectionDetailsResponse200Parts> {
+        self.parts()
+            .iter()
+            .filter(move |part| release_date(part).is_none_or(|date| date > today))
+    }
+
+    pub fn poster(&self, client: &mut TmdbClient) -> Result<DynamicImage, Error> {
+        client.resolve_image_path(self.details.poster_path.clone(), ImageKind::Poster)
+    }
+
+    pub fn backdrop(&self, client: &mut TmdbClient) -> Result<DynamicImage, Error> {
+        client.resolve_image_path(self.details.backdrop_path.clone(), ImageKind::Backdrop)
+    }
+}
+
+fn release_date(part: &CollectionDetailsResponse200Parts) -> Option<Date> {
+    part.release_date.parse().ok()
+}
+
+pub struct CollectionBuilder<'a> {
+    client: &'a TmdbClient,
+    id: u64,
+    language: Option<Cow<'a, str>>,
+}
+
+impl<'a> CollectionBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, id: u64) -> Self {
+        Self {
+            client,
+            id,
+            language: client.language.clone(),
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn load(self) -> Result<Collection, Error> {
+        let details: CollectionDetailsResponse200 = self.client.get_details(
+            "https://api.themoviedb.org/3/collection/{collection_id}",
+            &format!("/3/collection/{}", self.id),
+            self.language,
+            &[],
+        )?;
+        Ok(details.into())
+    }
+
+    /// The posters and backdrops of the collection. Images without a language
+    /// are always included.
+    pub fn images(self) -> Result<CollectionImagesResponse200, Error> {
+        let include_image_language = self
+            .language
+            .as_deref()
+            .map(|language| format!("{},null", language.split('-').next().unwrap_or(language)));
+        Ok(
+            tmdb_easy_raw::parametrized_functions::collection_images_with_parameter(
+                &self.client.client,
+                &self.client.api_key,
+                self.id as _,
+                CollectionImagesParameter {
+                    include_image_language: include_image_language.map(Into::into),
+                    language: self.language,
+                },
+            )?,
+        )
+    }
+
+    pub fn translations(self) -> Result<CollectionTranslationsResponse200, Error> {
+        Ok(
+            tmdb_easy_raw::parametrized_functions::collection_translations(
+                &self.client.client,
+                &self.client.api_key,
+                self.id as _,
+            )?,
+        )
+    }
+}
+
+#[test]
+fn collection_parts_by_release_date() {
+    let details: CollectionDetailsResponse200 = serde_json::from_str(
+        r#"{"id": 10, "name": "Star Wars Collection", "parts": [
+            {"id": 3, "title": "Return of the Jedi", "release_date": "1983-05-25"},
+            {"id": 4, "title": "Untitled", "release_date": ""},
+            {"id": 1, "title": "A New Hope", "release_date": "1977-05-25"},
+            {"id": 5, "title": "Announced", "release_date": "2099-01-01"}
+        ]}"#,
+    )
+    .unwrap();
+    let collection = Collection::from(details);
+    let ids: Vec<_> = collection.parts().iter().map(|part| part.id).collect();
+    assert_eq!(ids, [1, 3, 5, 4]);
+    let today = Date::new(2024, 1, 1);
+    assert_eq!(collection.released(today).count(), 2);
+    let upcoming: Vec<_> = collection.upcoming(today).map(|part| part.id).collect();
+    assert_eq!(upcoming, [5, 4]);
+}
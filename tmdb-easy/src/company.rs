@@ -0,0 +1,267 @@
+//! Production companies and tv networks. tmdb models them alike, both have
+//! logos and alternative names.
+
+use image::DynamicImage;
+use tmdb_easy_raw::types::{
+    CompanyAlternativeNamesResponse200Results, CompanyDetailsResponse200,
+    CompanyImagesResponse200Logos, NetworkDetailsResponse200,
+};
+
+use crate::{
+    client::{ImageKind, TmdbClient},
+    discover::{FilterList, movie::DiscoverMovieBuilder, tv::DiscoverTvBuilder},
+    error::Error,
+    id::{CompanyId, NetworkId},
+};
+
+/// Another name of a company or network, e.g. an abbreviation.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct AlternativeName {
+    pub name: String,
+    /// E.g. `abbreviation`, often empty.
+    #[serde(rename = "type")]
+    pub name_type: String,
+}
+
+/// A logo of a company or network.
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct Logo {
+    pub file_path: String,
+    /// E.g. `.svg` or `.png`.
+    pub file_type: String,
+    pub width: i64,
+    pub height: i64,
+    pub aspect_ratio: f64,
+    pub vote_average: f64,
+}
+
+impl From<CompanyAlternativeNamesResponse200Results> for AlternativeName {
+    fn from(value: CompanyAlternativeNamesResponse200Results) -> Self {
+        Self {
+            name: value.name,
+            name_type: value.r#type,
+        }
+    }
+}
+
+impl From<CompanyImagesResponse200Logos> for Logo {
+    fn from(value: CompanyImagesResponse200Logos) -> Self {
+        Self {
+            file_path: value.file_path,
+            file_type: value.file_type,
+            width: value.width,
+            height: value.height,
+            aspect_ratio: value.aspect_ratio,
+            vote_average: value.vote_average,
+        }
+    }
+}
+
+impl Logo {
+    pub fn image(&self, client: &mut TmdbClient) -> Result<DynamicImage, Error> {
+        client.resolve_image_path(self.file_path.clone(), ImageKind::Logo)
+    }
+}
+
+/// The network endpoints are misnamed in the raw crate, so their answers are
+/// deserialized directly.
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+struct AlternativeNames {
+    results: Vec<AlternativeName>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+struct Logos {
+    logos: Vec<Logo>,
+}
+
+/// A production company.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Company {
+    pub id: CompanyId,
+    pub name: String,
+    pub description: String,
+    pub headquarters: String,
+    pub homepage: String,
+    pub origin_country: String,
+    pub logo_path: String,
+}
+
+impl From<CompanyDetailsResponse200> for Company {
+    fn from(value: CompanyDetailsResponse200) -> Self {
+        Self {
+            id: CompanyId(value.id as _),
+            name: value.name,
+            description: value.description,
+            headquarters: value.headquarters,
+            homepage: value.homepage,
+            origin_country: value.origin_country,
+            logo_path: value.logo_path,
+        }
+    }
+}
+
+impl Company {
+    pub(crate) fn fetch(client: &TmdbClient, id: CompanyId) -> Result<Self, Error> {
+        Ok(tmdb_easy_raw::parametrized_functions::company_details(
+            &client.client,
+            &client.api_key,
+            id.0 as _,
+        )?
+        .into())
+    }
+
+    pub fn logo(&self, client: &mut TmdbClient) -> Result<DynamicImage, Error> {
+        client.resolve_image_path(self.logo_path.clone(), ImageKind::Logo)
+    }
+
+    pub fn alternative_names(&self, client: &TmdbClient) -> Result<Vec<AlternativeName>, Error> {
+        let response = tmdb_easy_raw::parametrized_functions::company_alternative_names(
+            &client.client,
+            &client.api_key,
+            self.id.0 as _,
+        )?;
+        Ok(response
+            .results
+            .into_iter()
+            .map(AlternativeName::from)
+            .collect())
+    }
+
+    /// All logos the company used, best rated first.
+    pub fn logos(&self, client: &TmdbClient) -> Result<Vec<Logo>, Error> {
+        let response = tmdb_easy_raw::parametrized_functions::company_images(
+            &client.client,
+            &client.api_key,
+            self.id.0 as _,
+        )?;
+        Ok(sorted_logos(
+            response.logos.into_iter().map(Logo::from).collect(),
+        ))
+    }
+
+    /// Prepares discovering movies produced by this company.
+    pub fn discover_movies<'a>(&self, client: &'a TmdbClient) -> DiscoverMovieBuilder<'a> {
+        client
+            .discover_movies()
+            .with_companies(FilterList::all([self.id]))
+    }
+
+    /// Prepares discovering tv series produced by this company.
+    pub fn discover_tv<'a>(&self, client: &'a TmdbClient) -> DiscoverTvBuilder<'a> {
+        client
+            .discover_tv()
+            .with_companies(FilterList::all([self.id]))
+    }
+}
+
+/// A tv network or streaming service, that airs tv series.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    pub id: NetworkId,
+    pub name: String,
+    pub headquarters: String,
+    pub homepage: String,
+    pub origin_country: String,
+    pub logo_path: String,
+}
+
+impl From<NetworkDetailsResponse200> for Network {
+    fn from(value: NetworkDetailsResponse200) -> Self {
+        Self {
+            id: NetworkId(value.id as _),
+            name: value.name,
+            headquarters: value.headquarters,
+            homepage: value.homepage,
+            origin_country: value.origin_country,
+            logo_path: value.logo_path,
+        }
+    }
+}
+
+impl Network {
+    pub(crate) fn fetch(client: &TmdbClient, id: NetworkId) -> Result<Self, Error> {
+        Ok(tmdb_easy_raw::parametrized_functions::network_details(
+            &client.client,
+            &client.api_key,
+            id.0 as _,
+        )?
+        .into())
+    }
+
+    pub fn logo(&self, client: &mut TmdbClient) -> Result<DynamicImage, Error> {
+        client.resolve_image_path(self.logo_path.clone(), ImageKind::Logo)
+    }
+
+    pub fn alternative_names(&self, client: &TmdbClient) -> Result<Vec<AlternativeName>, Error> {
+        // The raw crate names this endpoint `details_copy`.
+        let response: AlternativeNames = client.get_json(
+            "https://api.themoviedb.org/3/network/{network_id}/alternative_names",
+            &format!("/3/network/{}/alternative_names", self.id),
+            &[],
+        )?;
+        Ok(response.results)
+    }
+
+    /// All logos the network used, best rated first.
+    pub fn logos(&self, client: &TmdbClient) -> Result<Vec<Logo>, Error> {
+        // The raw crate names this endpoint `alternative_names_copy`.
+        let response: Logos = client.get_json(
+            "https://api.themoviedb.org/3/network/{network_id}/images",
+            &format!("/3/network/{}/images", self.id),
+            &[],
+        )?;
+        Ok(sorted_logos(response.logos))
+    }
+
+    /// Prepares discovering tv series aired by this network.
+    pub fn discover_tv<'a>(&self, client: &'a TmdbClient) -> DiscoverTvBuilder<'a> {
+        client
+            .discover_tv()
+            .with_networks(FilterList::all([self.id]))
+    }
+}
+
+fn sorted_logos(mut logos: Vec<Logo>) -> Vec<Logo> {
+    logos.sort_by(|a, b| b.vote_average.total_cmp(&a.vote_average));
+    logos
+}
+
+#[test]
+fn network_names_and_logos() {
+    let network: NetworkDetailsResponse200 = serde_json::from_str(
+        r#"{"id": 49, "name": "HBO", "origin_country": "US", "logo_path": "/hbo.png"}"#,
+    )
+    .unwrap();
+    let network = Network::from(network);
+    assert_eq!(network.id, NetworkId(49));
+    let names: AlternativeNames =
+        serde_json::from_str(r#"{"id": 49, "results": [{"name": "Home Box Office", "type": ""}]}"#)
+            .unwrap();
+    assert_eq!(names.results[0].name, "Home Box Office");
+    let logos: Logos = serde_json::from_str(
+        r#"{"id": 49, "logos": [
+            {"file_path": "/old.png", "file_type": ".png", "vote_average": 1.0},
+            {"file_path": "/new.svg", "file_type": ".svg", "vote_average": 5.3}
+        ]}"#,
+    )
+    .unwrap();
+    assert_eq!(sorted_logos(logos.logos)[0].file_path, "/new.svg");
+
+    let names: tmdb_easy_raw::types::CompanyAlternativeNamesResponse200 = serde_json::from_str(
+        r#"{"id": 1, "results": [{"name": "Lucasfilm Ltd.", "type": "abbreviation"}]}"#,
+    )
+    .unwrap();
+    let name = AlternativeName::from(names.results[0].clone());
+    assert_eq!(name.name_type, "abbreviation");
+    let images: tmdb_easy_raw::types::CompanyImagesResponse200 = serde_json::from_str(
+        r#"{"id": 1, "logos": [{"id": "5aa", "file_path": "/lucasfilm.png", "width": 500, "height": 200, "aspect_ratio": 2.5}]}"#,
+    )
+    .unwrap();
+    let logo = Logo::from(images.logos[0].clone());
+    assert_eq!((logo.width, logo.aspect_ratio), (500, 2.5));
+}
@@ -0,0 +1,262 @@
+//! The reference data of tmdb: countries, languages, primary translations,
+//! timezones and jobs. A snapshot of everything but the jobs is vendored, so
+//! [`Region`], [`Language`] and [`Locale`] can be checked without a request.
+
+use std::{fmt::Display, sync::OnceLock};
+
+use tmdb_easy_raw::types::{
+    ConfigurationCountriesResponse200, ConfigurationJobsResponse200,
+    ConfigurationLanguagesResponse200, ConfigurationTimezonesResponse200,
+};
+
+use crate::{
+    client::TmdbClient, error::Error, language::Language, localization::Locale, region::Region,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Country {
+    pub region: Region,
+    pub english_name: String,
+    /// Empty in the vendored snapshot.
+    pub native_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageInfo {
+    pub language: Language,
+    pub english_name: String,
+    /// The name in the language itself, often empty.
+    pub name: String,
+}
+
+/// The IANA timezones of a country, e.g. `Europe/Berlin` and
+/// `Europe/Busingen` for `DE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountryTimezones {
+    pub region: Region,
+    pub zones: Vec<String>,
+}
+
+/// A department of the crew, as used by credits and the job list.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Department {
+    Acting,
+    Art,
+    Camera,
+    CostumeAndMakeUp,
+    Crew,
+    Directing,
+    Editing,
+    Lighting,
+    Production,
+    Sound,
+    VisualEffects,
+    Writing,
+    Other(String),
+}
+
+impl From<String> for Department {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            // The job list calls it `Actors`.
+            "Acting" | "Actors" => Department::Acting,
+            "Art" => Department::Art,
+            "Camera" => Department::Camera,
+            "Costume & Make-Up" => Department::CostumeAndMakeUp,
+            "Crew" => Department::Crew,
+            "Directing" => Department::Directing,
+            "Editing" => Department::Editing,
+            "Lighting" => Department::Lighting,
+            "Production" => Department::Production,
+            "Sound" => Department::Sound,
+            "Visual Effects" => Department::VisualEffects,
+            "Writing" => Department::Writing,
+            _ => Department::Other(value),
+        }
+    }
+}
+
+impl Display for Department {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Department::Acting => write!(f, "Acting"),
+            Department::Art => write!(f, "Art"),
+            Department::Camera => write!(f, "Camera"),
+            Department::CostumeAndMakeUp => write!(f, "Costume & Make-Up"),
+            Department::Crew => write!(f, "Crew"),
+            Department::Directing => write!(f, "Directing"),
+            Department::Editing => write!(f, "Editing"),
+            Department::Lighting => write!(f, "Lighting"),
+            Department::Production => write!(f, "Production"),
+            Department::Sound => write!(f, "Sound"),
+            Department::VisualEffects => write!(f, "Visual Effects"),
+            Department::Writing => write!(f, "Writing"),
+            Department::Other(department) => write!(f, "{department}"),
+        }
+    }
+}
+
+/// The jobs tmdb knows in a department, e.g. `Director` in `Directing`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepartmentJobs {
+    pub department: Department,
+    pub jobs: Vec<String>,
+}
+
+impl From<ConfigurationJobsResponse200> for DepartmentJobs {
+    fn from(value: ConfigurationJobsResponse200) -> Self {
+        Self {
+            department: value.department.into(),
+            jobs: value.jobs,
+        }
+    }
+}
+
+impl DepartmentJobs {
+    pub(crate) fn fetch(client: &TmdbClient) -> Result<Vec<Self>, Error> {
+        Ok(
+            tmdb_easy_raw::functions::configuration_jobs(&client.client, &client.api_key)?
+                .into_iter()
+                .map(Self::from)
+                .collect(),
+        )
+    }
+}
+
+/// Countries, languages, primary translations and timezones. Entries with
+/// codes, that do not parse, are skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReferenceData {
+    pub countries: Vec<Country>,
+    pub languages: Vec<LanguageInfo>,
+    /// The locales tmdb offers translations in, e.g. `de-DE` and `pt-BR`.
+    pub primary_translations: Vec<Locale>,
+    pub timezones: Vec<CountryTimezones>,
+}
+
+impl ReferenceData {
+    fn from_raw(
+        countries: Vec<ConfigurationCountriesResponse200>,
+        languages: Vec<ConfigurationLanguagesResponse200>,
+        primary_translations: Vec<String>,
+        timezones: Vec<ConfigurationTimezonesResponse200>,
+    ) -> Self {
+        Self {
+            countries: countries
+                .into_iter()
+                .filter_map(|country| {
+                    Some(Country {
+                        region: country.iso_3166_1.parse().ok()?,
+                        english_name: country.english_name,
+                        native_name: country.native_name,
+                    })
+                })
+                .collect(),
+            languages: languages
+                .into_iter()
+                .filter_map(|language| {
+                    Some(LanguageInfo {
+                        language: language.iso_639_1.parse().ok()?,
+                        english_name: language.english_name,
+                        name: language.name,
+                    })
+                })
+                .collect(),
+            primary_translations: primary_translations
+                .iter()
+                .filter_map(|locale| locale.parse().ok())
+                .collect(),
+            timezones: timezones
+                .into_iter()
+                .filter_map(|country| {
+                    Some(CountryTimezones {
+                        region: country.iso_3166_1.parse().ok()?,
+                        zones: country.zones,
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// The snapshot vendored with this crate. It may miss countries or
+    /// languages tmdb added since, use [`TmdbClient::reference_data`] for
+    /// the current data.
+    pub fn snapshot() -> &'static Self {
+        static SNAPSHOT: OnceLock<ReferenceData> = OnceLock::new();
+        fn parse<T: serde::de::DeserializeOwned>(json: &str) -> T {
+            serde_json::from_str(json).expect("the snapshot is valid json")
+        }
+        SNAPSHOT.get_or_init(|| {
+            Self::from_raw(
+                parse(include_str!("../data/countries.json")),
+                parse(include_str!("../data/languages.json")),
+                parse(include_str!("../data/primary_translations.json")),
+                parse(include_str!("../data/timezones.json")),
+            )
+        })
+    }
+
+    pub(crate) fn fetch(client: &TmdbClient) -> Result<Self, Error> {
+        use tmdb_easy_raw::functions as raw;
+        Ok(Self::from_raw(
+            raw::configuration_countries(&client.client, &client.api_key, None)?,
+            raw::configuration_languages(&client.client, &client.api_key)?,
+            raw::configuration_primary_translations(&client.client, &client.api_key)?,
+            raw::configuration_timezones(&client.client, &client.api_key)?,
+        ))
+    }
+
+    pub fn country(&self, region: Region) -> Option<&Country> {
+        self.countries
+            .iter()
+            .find(|country| country.region == region)
+    }
+
+    pub fn language(&self, language: Language) -> Option<&LanguageInfo> {
+        self.languages.iter().find(|info| info.language == language)
+    }
+
+    /// Empty for unknown countries.
+    pub fn timezones(&self, region: Region) -> &[String] {
+        self.timezones
+            .iter()
+            .find(|country| country.region == region)
+            .map(|country| country.zones.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Whether tmdb offers translations in exactly this locale.
+    pub fn is_primary_translation(&self, locale: &Locale) -> bool {
+        self.primary_translations.contains(locale)
+    }
+}
+
+#[test]
+fn vendored_snapshot() {
+    let data = ReferenceData::snapshot();
+    let germany: Region = "DE".parse().unwrap();
+    assert_eq!(data.country(germany).unwrap().english_name, "Germany");
+    assert!(
+        data.timezones(germany)
+            .contains(&"Europe/Berlin".to_string())
+    );
+    assert_eq!(
+        data.language("xx".parse().unwrap()).unwrap().english_name,
+        "No Language"
+    );
+    assert!(data.is_primary_translation(&"pt-BR".parse().unwrap()));
+    assert!(!data.is_primary_translation(&"pt".parse().unwrap()));
+
+    let jobs: Vec<ConfigurationJobsResponse200> = serde_json::from_str(
+        r#"[{"department": "Costume & Make-Up", "jobs": ["Makeup Artist"]},
+            {"department": "Puppetry", "jobs": []}]"#,
+    )
+    .unwrap();
+    let jobs: Vec<DepartmentJobs> = jobs.into_iter().map(DepartmentJobs::from).collect();
+    assert_eq!(jobs[0].department, Department::CostumeAndMakeUp);
+    assert_eq!(jobs[0].department.to_string(), "Costume & Make-Up");
+    assert_eq!(
+        jobs[1].department,
+        Department::Other("Puppetry".to_string())
+    );
+}
@@ -0,0 +1,327 @@
+//! A single model for the cast and crew of movies, tv series, seasons and
+//! episodes. The raw crate has its own structs for every endpoint, these are
+//! converted into [`Credits`] with `From`.
+
+use std::collections::{BTreeMap, HashSet};
+
+use tmdb_easy_raw::types::{
+    MovieCreditsResponse200, TvEpisodeCreditsResponse200, TvSeasonAggregateCreditsResponse200,
+    TvSeasonCreditsResponse200, TvSeasonCreditsResponse200Crew,
+    TvSeriesAggregateCreditsResponse200, TvSeriesCreditsResponse200,
+};
+
+use crate::{
+    client::TmdbClient, configuration::Department, error::Error, id::PersonId, person::PersonLike,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CastMember {
+    pub id: PersonId,
+    pub credit_id: String,
+    pub name: String,
+    pub original_name: String,
+    pub profile_path: String,
+    pub known_for_department: Department,
+    pub popularity: f64,
+    pub character: String,
+    /// Position in the billing, lower is more prominent.
+    pub order: u32,
+    /// Only known for aggregate credits, each role is its own cast member.
+    pub episode_count: Option<u32>,
+    /// Guest stars only appear in a single episode.
+    pub guest_star: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrewMember {
+    pub id: PersonId,
+    pub credit_id: String,
+    pub name: String,
+    pub original_name: String,
+    pub profile_path: String,
+    pub known_for_department: Department,
+    pub popularity: f64,
+    pub department: Department,
+    pub job: String,
+    /// Only known for aggregate credits, each job is its own crew member.
+    pub episode_count: Option<u32>,
+}
+
+impl PersonLike for CastMember {
+    fn id(&self) -> u64 {
+        self.id.0
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn profile_path(&self, _client: &TmdbClient) -> Result<String, Error> {
+        Ok(self.profile_path.clone())
+    }
+}
+
+impl PersonLike for CrewMember {
+    fn id(&self) -> u64 {
+        self.id.0
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn profile_path(&self, _client: &TmdbClient) -> Result<String, Error> {
+        Ok(self.profile_path.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Credits {
+    pub cast: Vec<CastMember>,
+    pub crew: Vec<CrewMember>,
+}
+
+impl Credits {
+    /// The first `n` cast members by billing order. Guest stars come after the
+    /// regular cast. People with several roles, as in aggregate credits, are
+    /// only listed once, with their first role.
+    pub fn top_billed(&self, n: usize) -> Vec<&CastMember> {
+        let mut cast: Vec<_> = self.cast.iter().collect();
+        cast.sort_by_key(|member| (member.guest_star, member.order));
+        let mut seen = HashSet::new();
+        cast.retain(|member| seen.insert(member.id));
+        cast.truncate(n);
+        cast
+    }
+
+    pub fn directors(&self) -> impl Iterator<Item = &CrewMember> {
+        self.crew.iter().filter(|member| member.job == "Director")
+    }
+
+    /// Everyone in the `Writing` department, e.g. screenplay, story or novel.
+    pub fn writers(&self) -> impl Iterator<Item = &CrewMember> {
+        self.crew
+            .iter()
+            .filter(|member| member.department == Department::Writing)
+    }
+
+    pub fn crew_by_department(&self) -> BTreeMap<&Department, Vec<&CrewMember>> {
+        let mut result: BTreeMap<&Department, Vec<&CrewMember>> = BTreeMap::new();
+        for member in &self.crew {
+            result.entry(&member.department).or_default().push(member);
+        }
+        result
+    }
+}
+
+macro_rules! cast_member {
+    ($member:expr, guest_star: $guest_star:expr) => {{
+        let member = $member;
+        CastMember {
+            id: PersonId(member.id as _),
+            credit_id: member.credit_id,
+            name: member.name,
+            original_name: member.original_name,
+            profile_path: member.profile_path,
+            known_for_department: member.known_for_department.into(),
+            popularity: member.popularity,
+            character: member.character,
+            order: member.order as _,
+            episode_count: None,
+            guest_star: $guest_star,
+        }
+    }};
+}
+
+macro_rules! crew_member {
+    ($member:expr) => {{
+        let member = $member;
+        CrewMember {
+            id: PersonId(member.id as _),
+            credit_id: member.credit_id,
+            name: member.name,
+            original_name: member.original_name,
+            profile_path: member.profile_path,
+            known_for_department: member.known_for_department.into(),
+            popularity: member.popularity,
+            department: member.department.into(),
+            job: member.job,
+            episode_count: None,
+        }
+    }};
+}
+
+/// Flattens the nested roles and jobs of aggregate credits.
+macro_rules! aggregate_credits {
+    ($credits:expr, crew_profile_path: |$crew:ident| $crew_profile_path:expr) => {{
+        let credits = $credits;
+        let cast = credits
+            .cast
+            .into_iter()
+            .flat_map(|member| {
+                member.roles.into_iter().map(move |role| CastMember {
+                    id: PersonId(member.id as _),
+                    credit_id: role.credit_id,
+                    name: member.name.clone(),
+                    original_name: member.original_name.clone(),
+                    profile_path: member.profile_path.clone(),
+                    known_for_department: member.known_for_department.clone().into(),
+                    popularity: member.popularity,
+                    character: role.character,
+                    order: member.order as _,
+                    episode_count: Some(role.episode_count as _),
+                    guest_star: false,
+                })
+            })
+            .collect();
+        let crew = credits
+            .crew
+            .into_iter()
+            .flat_map(|$crew| {
+                let profile_path: String = $crew_profile_path;
+                $crew.jobs.into_iter().map(move |job| CrewMember {
+                    id: PersonId($crew.id as _),
+                    credit_id: job.credit_id,
+                    name: $crew.name.clone(),
+                    original_name: $crew.original_name.clone(),
+                    profile_path: profile_path.clone(),
+                    known_for_department: $crew.known_for_department.clone().into(),
+                    popularity: $crew.popularity,
+                    department: $crew.department.clone().into(),
+                    job: job.job,
+                    episode_count: Some(job.episode_count as _),
+                })
+            })
+            .collect();
+        Credits { cast, crew }
+    }};
+}
+
+impl From<MovieCreditsResponse200> for Credits {
+    fn from(credits: MovieCreditsResponse200) -> Self {
+        Self {
+            cast: credits
+                .cast
+                .into_iter()
+                .map(|member| cast_member!(member, guest_star: false))
+                .collect(),
+            crew: credits
+                .crew
+                .into_iter()
+                .map(|member| crew_member!(member))
+                .collect(),
+        }
+    }
+}
+
+impl From<TvSeriesCreditsResponse200> for Credits {
+    fn from(credits: TvSeriesCreditsResponse200) -> Self {
+        Self {
+            cast: credits
+                .cast
+                .into_iter()
+                .map(|member| cast_member!(member, guest_star: false))
+                .collect(),
+            crew: credits
+                .crew
+                .into_iter()
+                .map(|member| crew_member!(member))
+                .collect(),
+        }
+    }
+}
+
+impl From<TvSeasonCreditsResponse200> for Credits {
+    fn from(credits: TvSeasonCreditsResponse200) -> Self {
+        Self {
+            cast: credits
+                .cast
+                .into_iter()
+                .map(|member| cast_member!(member, guest_star: false))
+                .collect(),
+            // The season crew has no profile path in the schema.
+            crew: credits
+                .crew
+                .into_iter()
+                .map(|member: TvSeasonCreditsResponse200Crew| CrewMember {
+                    id: PersonId(member.id as _),
+                    credit_id: member.credit_id,
+                    name: member.name,
+                    original_name: member.original_name,
+                    profile_path: String::new(),
+                    known_for_department: member.known_for_department.into(),
+                    popularity: member.popularity,
+                    department: member.department.into(),
+                    job: member.job,
+                    episode_count: None,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<TvEpisodeCreditsResponse200> for Credits {
+    fn from(credits: TvEpisodeCreditsResponse200) -> Self {
+        let cast = credits
+            .cast
+            .into_iter()
+            .map(|member| cast_member!(member, guest_star: false));
+        let guest_stars = credits
+            .guest_stars
+            .into_iter()
+            .map(|member| cast_member!(member, guest_star: true));
+        Self {
+            cast: cast.chain(guest_stars).collect(),
+            crew: credits
+                .crew
+                .into_iter()
+                .map(|member| crew_member!(member))
+                .collect(),
+        }
+    }
+}
+
+impl From<TvSeriesAggregateCreditsResponse200> for Credits {
+    fn from(credits: TvSeriesAggregateCreditsResponse200) -> Self {
+        aggregate_credits!(credits, crew_profile_path: |member| member.profile_path.clone())
+    }
+}
+
+impl From<TvSeasonAggregateCreditsResponse200> for Credits {
+    fn from(credits: TvSeasonAggregateCreditsResponse200) -> Self {
+        // The season crew has no profile path in the schema.
+        aggregate_credits!(credits, crew_profile_path: |member| String::new())
+    }
+}
+
+#[test]
+fn aggregate_credits_are_flattened() {
+    let credits: TvSeriesAggregateCreditsResponse200 = serde_json::from_str(
+        r#"{
+            "cast": [{"id": 1, "name": "A", "order": 1, "roles": [
+                {"character": "X", "episode_count": 3},
+                {"character": "Y", "episode_count": 1}
+            ]}, {"id": 2, "name": "B", "order": 0, "roles": [{"character": "Z", "episode_count": 7}]}],
+            "crew": [{"id": 3, "name": "C", "department": "Directing", "jobs": [
+                {"job": "Director", "episode_count": 2}
+            ]}, {"id": 4, "name": "D", "department": "Writing", "jobs": [
+                {"job": "Writer", "episode_count": 4},
+                {"job": "Story", "episode_count": 1}
+            ]}]
+        }"#,
+    )
+    .unwrap();
+    let credits = Credits::from(credits);
+    assert_eq!(credits.cast.len(), 3);
+    assert_eq!(credits.cast[1].episode_count, Some(1));
+    assert_eq!(credits.top_billed(1)[0].character, "Z");
+    let top_billed: Vec<_> = credits
+        .top_billed(3)
+        .iter()
+        .map(|member| member.character.as_str())
+        .collect();
+    assert_eq!(top_billed, ["Z", "X"]);
+    assert_eq!(credits.directors().next().unwrap().name, "C");
+    assert_eq!(credits.writers().count(), 2);
+    assert_eq!(credits.crew_by_department()[&Department::Writing].len(), 2);
+}
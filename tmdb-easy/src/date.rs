@@ -0,0 +1,148 @@
+use std::{fmt::Display, str::FromStr};
+
+/// A calendar date as tmdb expects it in its query parameters, formatted as
+/// `YYYY-MM-DD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    pub fn new(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// The number of days in `month` of `year`, `0` for months outside of
+    /// `1..=12`.
+    fn days_in_month(year: u16, month: u8) -> u8 {
+        let leap_year =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap_year => 29,
+            2 => 28,
+            _ => 0,
+        }
+    }
+
+    /// The number of days from `self` to `other`, negative if `other` is
+    /// earlier.
+    pub fn days_until(self, other: Date) -> i64 {
+        other.day_number() - self.day_number()
+    }
+
+    /// The number of days since `0000-03-01`. Starting the year in march
+    /// puts the leap day at its end.
+    fn day_number(self) -> i64 {
+        let (year, month) = if self.month <= 2 {
+            (self.year as i64 - 1, self.month as i64 + 9)
+        } else {
+            (self.year as i64, self.month as i64 - 3)
+        };
+        365 * year + year.div_euclid(4) - year.div_euclid(100)
+            + year.div_euclid(400)
+            + (153 * month + 2) / 5
+            + self.day as i64
+            - 1
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid date, expected YYYY-MM-DD: {0:?}")]
+pub struct InvalidDate(pub String);
+
+impl FromStr for Date {
+    type Err = InvalidDate;
+
+    /// Fails for months and days, that do not exist, e.g. `2023-13-45` or
+    /// `2023-02-29`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidDate(s.to_string());
+        let mut parts = s.splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(invalid);
+        let year = next()?.parse().map_err(|_| invalid())?;
+        let month = next()?.parse().map_err(|_| invalid())?;
+        let day = next()?.parse().map_err(|_| invalid())?;
+        if day == 0 || day > Self::days_in_month(year, month) {
+            return Err(invalid());
+        }
+        Ok(Self { year, month, day })
+    }
+}
+
+/// A point in time in UTC, as tmdb uses it for reviews, e.g.
+/// `2016-01-05T02:27:49.823Z`. Fractions of seconds are dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DateTime {
+    pub date: Date,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}Z",
+            self.date, self.hour, self.minute, self.second
+        )
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = InvalidDate;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidDate(s.to_string());
+        let (date, time) = s.split_once(['T', ' ']).ok_or_else(invalid)?;
+        let time = time.trim_end_matches(" UTC").trim_end_matches('Z');
+        let time = time.split_once('.').map_or(time, |(time, _)| time);
+        let mut parts = time.splitn(3, ':');
+        let mut next = || parts.next().ok_or_else(invalid);
+        let hour = next()?.parse().map_err(|_| invalid())?;
+        let minute = next()?.parse().map_err(|_| invalid())?;
+        let second = next()?.parse().map_err(|_| invalid())?;
+        Ok(Self {
+            date: date.parse()?,
+            hour,
+            minute,
+            second,
+        })
+    }
+}
+
+#[test]
+fn date_round_trip() {
+    let date: Date = "2023-07-09".parse().unwrap();
+    assert_eq!(date, Date::new(2023, 7, 9));
+    assert_eq!(date.to_string(), "2023-07-09");
+    assert!("".parse::<Date>().is_err());
+    assert!("2023-07".parse::<Date>().is_err());
+    assert!("2023-13-45".parse::<Date>().is_err());
+    assert!("2023-00-10".parse::<Date>().is_err());
+    assert!("2023-04-31".parse::<Date>().is_err());
+    assert!("2023-02-29".parse::<Date>().is_err());
+    assert_eq!(
+        "2024-02-29".parse::<Date>().unwrap(),
+        Date::new(2024, 2, 29)
+    );
+    assert!("1900-02-29".parse::<Date>().is_err());
+    assert_eq!(Date::new(2024, 2, 20).days_until(Date::new(2024, 3, 5)), 14);
+    assert_eq!(
+        Date::new(2023, 12, 31).days_until(Date::new(2023, 1, 1)),
+        -364
+    );
+    let time: DateTime = "2016-01-05T02:27:49.823Z".parse().unwrap();
+    assert_eq!(time.to_string(), "2016-01-05T02:27:49Z");
+    assert_eq!("2016-01-05 02:27:49 UTC".parse::<DateTime>().unwrap(), time);
+}
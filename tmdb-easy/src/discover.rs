@@ -0,0 +1,141 @@
+use std::{
+    fmt::Display,
+    ops::{RangeFrom, RangeFull, RangeInclusive, RangeToInclusive},
+};
+
+pub mod movie;
+pub mod tv;
+
+/// A list of values, where either all of them (`AND`) or any of them (`OR`)
+/// have to match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterList<T> {
+    All(Vec<T>),
+    Any(Vec<T>),
+}
+
+impl<T> FilterList<T> {
+    pub fn all<U: Into<T>>(values: impl IntoIterator<Item = U>) -> Self {
+        Self::All(values.into_iter().map(Into::into).collect())
+    }
+
+    pub fn any<U: Into<T>>(values: impl IntoIterator<Item = U>) -> Self {
+        Self::Any(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Display> Display for FilterList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (values, separator) = match self {
+            FilterList::All(values) => (values, ','),
+            FilterList::Any(values) => (values, '|'),
+        };
+        for (i, value) in values.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{separator}")?;
+            }
+            write!(f, "{value}")?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn comma_separated<T: Display>(values: impl IntoIterator<Item = T>) -> String {
+    values
+        .into_iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Ranges which tmdb can express with its `.gte` and `.lte` parameters. So
+/// `a..=b`, `a..`, `..=b` and `..`.
+pub trait InclusiveRange<T> {
+    fn into_bounds(self) -> (Option<T>, Option<T>);
+}
+
+impl<T> InclusiveRange<T> for RangeInclusive<T> {
+    fn into_bounds(self) -> (Option<T>, Option<T>) {
+        let (start, end) = self.into_inner();
+        (Some(start), Some(end))
+    }
+}
+
+impl<T> InclusiveRange<T> for RangeFrom<T> {
+    fn into_bounds(self) -> (Option<T>, Option<T>) {
+        (Some(self.start), None)
+    }
+}
+
+impl<T> InclusiveRange<T> for RangeToInclusive<T> {
+    fn into_bounds(self) -> (Option<T>, Option<T>) {
+        (None, Some(self.end))
+    }
+}
+
+impl<T> InclusiveRange<T> for RangeFull {
+    fn into_bounds(self) -> (Option<T>, Option<T>) {
+        (None, None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    Ascending,
+    #[default]
+    Descending,
+}
+
+impl Display for SortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortOrder::Ascending => write!(f, "asc"),
+            SortOrder::Descending => write!(f, "desc"),
+        }
+    }
+}
+
+/// How a title is made available by a watch provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonetizationType {
+    Flatrate,
+    Free,
+    Ads,
+    Rent,
+    Buy,
+}
+
+impl MonetizationType {
+    pub const ALL: [MonetizationType; 5] = [
+        MonetizationType::Flatrate,
+        MonetizationType::Free,
+        MonetizationType::Ads,
+        MonetizationType::Rent,
+        MonetizationType::Buy,
+    ];
+}
+
+impl Display for MonetizationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MonetizationType::Flatrate => write!(f, "flatrate"),
+            MonetizationType::Free => write!(f, "free"),
+            MonetizationType::Ads => write!(f, "ads"),
+            MonetizationType::Rent => write!(f, "rent"),
+            MonetizationType::Buy => write!(f, "buy"),
+        }
+    }
+}
+
+#[test]
+fn filter_list_encoding() {
+    use crate::id::GenreId;
+    assert_eq!(FilterList::<GenreId>::all([28u64, 12]).to_string(), "28,12");
+    assert_eq!(FilterList::<GenreId>::any([28u64, 12]).to_string(), "28|12");
+    assert_eq!(FilterList::<GenreId>::all([GenreId(18)]).to_string(), "18");
+    assert_eq!(
+        FilterList::<MonetizationType>::any([MonetizationType::Flatrate, MonetizationType::Free])
+            .to_string(),
+        "flatrate|free"
+    );
+}
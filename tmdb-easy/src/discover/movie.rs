@@ -0,0 +1,350 @@
+use std::fmt::Display;
+
+use tmdb_easy_raw::types::{DiscoverMovieResponse200, DiscoverMovieResponse200Results};
+
+use crate::{
+    client::TmdbClient,
+    date::Date,
+    discover::{FilterList, InclusiveRange, MonetizationType, SortOrder, comma_separated},
+    error::Error,
+    id::{CompanyId, GenreId, KeywordId, PersonId, WatchProviderId},
+    language::Language,
+    localization::Locale,
+    movie::ReleaseType,
+    page::{PagedBuilder, PagedResponse, result_page},
+    region::Region,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MovieSortBy {
+    OriginalTitle,
+    #[default]
+    Popularity,
+    Revenue,
+    PrimaryReleaseDate,
+    Title,
+    VoteAverage,
+    VoteCount,
+}
+
+impl Display for MovieSortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MovieSortBy::OriginalTitle => write!(f, "original_title"),
+            MovieSortBy::Popularity => write!(f, "popularity"),
+            MovieSortBy::Revenue => write!(f, "revenue"),
+            MovieSortBy::PrimaryReleaseDate => write!(f, "primary_release_date"),
+            MovieSortBy::Title => write!(f, "title"),
+            MovieSortBy::VoteAverage => write!(f, "vote_average"),
+            MovieSortBy::VoteCount => write!(f, "vote_count"),
+        }
+    }
+}
+
+result_page!(DiscoverMovieResponse200, DiscoverMovieResponse200Results);
+
+pub type DiscoverMovieResponse<'a> = PagedResponse<DiscoverMovieBuilder<'a>>;
+
+pub struct DiscoverMovieBuilder<'a> {
+    client: &'a TmdbClient,
+    parameters: tmdb_easy_raw::parameter_types::DiscoverMovieParameter<'a>,
+}
+
+impl<'a> DiscoverMovieBuilder<'a> {
+    pub fn new(client: &'a TmdbClient) -> Self {
+        Self {
+            client,
+            parameters: tmdb_easy_raw::parameter_types::DiscoverMovieParameter {
+                language: client.language.clone(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.parameters.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_page(mut self, page: i64) -> Self {
+        self.parameters.page = Some(page);
+        self
+    }
+
+    pub fn with_sort_by(mut self, sort_by: MovieSortBy, order: SortOrder) -> Self {
+        self.parameters.sort_by = Some(format!("{sort_by}.{order}").into());
+        self
+    }
+
+    pub fn include_adult(mut self, include_adult: bool) -> Self {
+        self.parameters.include_adult = Some(include_adult);
+        self
+    }
+
+    pub fn include_video(mut self, include_video: bool) -> Self {
+        self.parameters.include_video = Some(include_video);
+        self
+    }
+
+    /// Matches movies released in this year, in any country and with any
+    /// release type.
+    pub fn with_year(mut self, year: u16) -> Self {
+        self.parameters.year = Some(year.into());
+        self
+    }
+
+    pub fn with_primary_release_year(mut self, year: u16) -> Self {
+        self.parameters.primary_release_year = Some(year.into());
+        self
+    }
+
+    pub fn with_primary_release_date(mut self, range: impl InclusiveRange<Date>) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.primary_release_date_gte = gte.map(|d| d.to_string().into());
+        self.parameters.primary_release_date_lte = lte.map(|d| d.to_string().into());
+        self
+    }
+
+    /// Filters on all release dates, use in conjunction with
+    /// [`Self::with_region`] and [`Self::with_release_type`].
+    pub fn with_release_date(mut self, range: impl InclusiveRange<Date>) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.release_date_gte = gte.map(|d| d.to_string().into());
+        self.parameters.release_date_lte = lte.map(|d| d.to_string().into());
+        self
+    }
+
+    pub fn with_release_type(mut self, release_types: FilterList<ReleaseType>) -> Self {
+        self.parameters.with_release_type = Some(release_types.to_string().into());
+        self
+    }
+
+    pub fn with_region(mut self, region: Region) -> Self {
+        self.parameters.region = Some(region.to_string().into());
+        self
+    }
+
+    pub fn with_vote_average(mut self, range: impl InclusiveRange<f64>) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.vote_average_gte = gte;
+        self.parameters.vote_average_lte = lte;
+        self
+    }
+
+    pub fn with_vote_count(mut self, range: impl InclusiveRange<u32>) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.vote_count_gte = gte.map(Into::into);
+        self.parameters.vote_count_lte = lte.map(Into::into);
+        self
+    }
+
+    /// Runtime in minutes.
+    pub fn with_runtime(mut self, range: impl InclusiveRange<u32>) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.with_runtime_gte = gte.map(Into::into);
+        self.parameters.with_runtime_lte = lte.map(Into::into);
+        self
+    }
+
+    /// Only movies with exactly this certification in `country`.
+    pub fn with_certification<'b: 'a>(mut self, country: Region, certification: &'b str) -> Self {
+        self.parameters.certification_country = Some(country.to_string().into());
+        self.parameters.certification = Some(certification.into());
+        self
+    }
+
+    /// Only movies with a certification in `range` in `country`. The order is
+    /// the one tmdb uses for the certifications of that country.
+    pub fn with_certification_range<'b: 'a>(
+        mut self,
+        country: Region,
+        range: impl InclusiveRange<&'b str>,
+    ) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.certification_country = Some(country.to_string().into());
+        self.parameters.certification_gte = gte.map(Into::into);
+        self.parameters.certification_lte = lte.map(Into::into);
+        self
+    }
+
+    pub fn with_genres(mut self, genres: FilterList<GenreId>) -> Self {
+        self.parameters.with_genres = Some(genres.to_string().into());
+        self
+    }
+
+    pub fn without_genres<T: Into<GenreId>>(mut self, genres: impl IntoIterator<Item = T>) -> Self {
+        self.parameters.without_genres =
+            Some(comma_separated(genres.into_iter().map(Into::into)).into());
+        self
+    }
+
+    pub fn with_cast(mut self, cast: FilterList<PersonId>) -> Self {
+        self.parameters.with_cast = Some(cast.to_string().into());
+        self
+    }
+
+    pub fn with_crew(mut self, crew: FilterList<PersonId>) -> Self {
+        self.parameters.with_crew = Some(crew.to_string().into());
+        self
+    }
+
+    /// Matches people in either the cast or the crew.
+    pub fn with_people(mut self, people: FilterList<PersonId>) -> Self {
+        self.parameters.with_people = Some(people.to_string().into());
+        self
+    }
+
+    pub fn with_companies(mut self, companies: FilterList<CompanyId>) -> Self {
+        self.parameters.with_companies = Some(companies.to_string().into());
+        self
+    }
+
+    pub fn without_companies<T: Into<CompanyId>>(
+        mut self,
+        companies: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.parameters.without_companies =
+            Some(comma_separated(companies.into_iter().map(Into::into)).into());
+        self
+    }
+
+    pub fn with_keywords(mut self, keywords: FilterList<KeywordId>) -> Self {
+        self.parameters.with_keywords = Some(keywords.to_string().into());
+        self
+    }
+
+    pub fn without_keywords<T: Into<KeywordId>>(
+        mut self,
+        keywords: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.parameters.without_keywords =
+            Some(comma_separated(keywords.into_iter().map(Into::into)).into());
+        self
+    }
+
+    pub fn with_origin_country(mut self, country: Region) -> Self {
+        self.parameters.with_origin_country = Some(country.to_string().into());
+        self
+    }
+
+    pub fn with_original_language(mut self, language: Language) -> Self {
+        self.parameters.with_original_language = Some(language.to_string().into());
+        self
+    }
+
+    /// Region used by the watch provider and monetization filters.
+    pub fn with_watch_region(mut self, region: Region) -> Self {
+        self.parameters.watch_region = Some(region.to_string().into());
+        self
+    }
+
+    pub fn with_watch_providers(mut self, providers: FilterList<WatchProviderId>) -> Self {
+        self.parameters.with_watch_providers = Some(providers.to_string().into());
+        self
+    }
+
+    pub fn without_watch_providers<T: Into<WatchProviderId>>(
+        mut self,
+        providers: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.parameters.without_watch_providers =
+            Some(comma_separated(providers.into_iter().map(Into::into)).into());
+        self
+    }
+
+    pub fn with_watch_monetization_types(
+        mut self,
+        monetization_types: FilterList<MonetizationType>,
+    ) -> Self {
+        self.parameters.with_watch_monetization_types = Some(monetization_types.to_string().into());
+        self
+    }
+
+    pub fn search(self) -> Result<DiscoverMovieResponse<'a>, Error> {
+        PagedResponse::load(self)
+    }
+}
+
+impl PagedBuilder for DiscoverMovieBuilder<'_> {
+    type Page = DiscoverMovieResponse200;
+
+    fn fetch_page(&self) -> Result<Self::Page, Error> {
+        Ok(
+            tmdb_easy_raw::parametrized_functions::discover_movie_with_parameter(
+                &self.client.client,
+                self.client.api_key.as_ref(),
+                self.parameters.clone(),
+            )?,
+        )
+    }
+
+    fn set_page(&mut self, page: i64) {
+        self.parameters.page = Some(page);
+    }
+}
+
+#[test]
+fn discover_movie_query_values() {
+    let client = TmdbClient::new("offline").with_language("de-DE".parse::<Locale>().unwrap());
+    let builder = DiscoverMovieBuilder::new(&client)
+        .with_sort_by(MovieSortBy::VoteAverage, SortOrder::Descending)
+        .with_genres(FilterList::all([28u64, 12]))
+        .without_genres([27u64])
+        .with_cast(FilterList::any([287u64, 819]))
+        .with_watch_monetization_types(FilterList::any([
+            MonetizationType::Flatrate,
+            MonetizationType::Free,
+        ]))
+        .with_release_type(FilterList::any([
+            ReleaseType::Theatrical,
+            ReleaseType::Digital,
+        ]))
+        .with_release_date(Date::new(2023, 1, 1)..=Date::new(2023, 12, 31))
+        .with_primary_release_date(..=Date::new(2010, 6, 30))
+        .with_vote_average(7.5..)
+        .with_vote_count(100..=5000)
+        .with_runtime(..)
+        .with_region("de".parse().unwrap())
+        .with_certification_range("DE".parse().unwrap(), "6"..="16")
+        .with_page(3);
+    let parameters = &builder.parameters;
+    assert_eq!(parameters.language.as_deref(), Some("de-DE"));
+    assert_eq!(parameters.sort_by.as_deref(), Some("vote_average.desc"));
+    assert_eq!(parameters.with_genres.as_deref(), Some("28,12"));
+    assert_eq!(parameters.without_genres.as_deref(), Some("27"));
+    assert_eq!(parameters.with_cast.as_deref(), Some("287|819"));
+    assert_eq!(
+        parameters.with_watch_monetization_types.as_deref(),
+        Some("flatrate|free")
+    );
+    assert_eq!(parameters.with_release_type.as_deref(), Some("3|4"));
+    assert_eq!(parameters.release_date_gte.as_deref(), Some("2023-01-01"));
+    assert_eq!(parameters.release_date_lte.as_deref(), Some("2023-12-31"));
+    assert_eq!(parameters.primary_release_date_gte, None);
+    assert_eq!(
+        parameters.primary_release_date_lte.as_deref(),
+        Some("2010-06-30")
+    );
+    assert_eq!(
+        (parameters.vote_average_gte, parameters.vote_average_lte),
+        (Some(7.5), None)
+    );
+    assert_eq!(
+        (parameters.vote_count_gte, parameters.vote_count_lte),
+        (Some(100.0), Some(5000.0))
+    );
+    assert_eq!(
+        (parameters.with_runtime_gte, parameters.with_runtime_lte),
+        (None, None)
+    );
+    assert_eq!(parameters.region.as_deref(), Some("DE"));
+    assert_eq!(parameters.certification_country.as_deref(), Some("DE"));
+    assert_eq!(
+        (
+            parameters.certification_gte.as_deref(),
+            parameters.certification_lte.as_deref()
+        ),
+        (Some("6"), Some("16"))
+    );
+    assert_eq!(parameters.page, Some(3));
+}
@@ -0,0 +1,301 @@
+use std::fmt::Display;
+
+use tmdb_easy_raw::types::{DiscoverTvResponse200, DiscoverTvResponse200Results};
+
+use crate::{
+    client::TmdbClient,
+    date::Date,
+    discover::{FilterList, InclusiveRange, MonetizationType, SortOrder, comma_separated},
+    error::Error,
+    id::{CompanyId, GenreId, KeywordId, NetworkId, WatchProviderId},
+    language::Language,
+    localization::Locale,
+    page::{PagedBuilder, PagedResponse, result_page},
+    region::Region,
+    timezone::Timezone,
+    tv::{SeriesStatus, SeriesType},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TvSortBy {
+    FirstAirDate,
+    Name,
+    OriginalName,
+    #[default]
+    Popularity,
+    VoteAverage,
+    VoteCount,
+}
+
+impl Display for TvSortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TvSortBy::FirstAirDate => write!(f, "first_air_date"),
+            TvSortBy::Name => write!(f, "name"),
+            TvSortBy::OriginalName => write!(f, "original_name"),
+            TvSortBy::Popularity => write!(f, "popularity"),
+            TvSortBy::VoteAverage => write!(f, "vote_average"),
+            TvSortBy::VoteCount => write!(f, "vote_count"),
+        }
+    }
+}
+
+result_page!(DiscoverTvResponse200, DiscoverTvResponse200Results);
+
+pub type DiscoverTvResponse<'a> = PagedResponse<DiscoverTvBuilder<'a>>;
+
+pub struct DiscoverTvBuilder<'a> {
+    client: &'a TmdbClient,
+    parameters: tmdb_easy_raw::parameter_types::DiscoverTvParameter<'a>,
+}
+
+impl<'a> DiscoverTvBuilder<'a> {
+    pub fn new(client: &'a TmdbClient) -> Self {
+        Self {
+            client,
+            parameters: tmdb_easy_raw::parameter_types::DiscoverTvParameter {
+                language: client.language.clone(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.parameters.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_page(mut self, page: i64) -> Self {
+        self.parameters.page = Some(page);
+        self
+    }
+
+    pub fn with_sort_by(mut self, sort_by: TvSortBy, order: SortOrder) -> Self {
+        self.parameters.sort_by = Some(format!("{sort_by}.{order}").into());
+        self
+    }
+
+    pub fn include_adult(mut self, include_adult: bool) -> Self {
+        self.parameters.include_adult = Some(include_adult);
+        self
+    }
+
+    pub fn include_null_first_air_dates(mut self, include_null_first_air_dates: bool) -> Self {
+        self.parameters.include_null_first_air_dates = Some(include_null_first_air_dates);
+        self
+    }
+
+    pub fn screened_theatrically(mut self, screened_theatrically: bool) -> Self {
+        self.parameters.screened_theatrically = Some(screened_theatrically);
+        self
+    }
+
+    /// Timezone used for the air date filters, e.g. `America/New_York`.
+    pub fn with_timezone(mut self, timezone: Timezone) -> Self {
+        self.parameters.timezone = Some(timezone.to_string().into());
+        self
+    }
+
+    pub fn with_first_air_date_year(mut self, year: u16) -> Self {
+        self.parameters.first_air_date_year = Some(year.into());
+        self
+    }
+
+    pub fn with_first_air_date(mut self, range: impl InclusiveRange<Date>) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.first_air_date_gte = gte.map(|d| d.to_string().into());
+        self.parameters.first_air_date_lte = lte.map(|d| d.to_string().into());
+        self
+    }
+
+    /// Matches series with any episode airing in `range`.
+    pub fn with_air_date(mut self, range: impl InclusiveRange<Date>) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.air_date_gte = gte.map(|d| d.to_string().into());
+        self.parameters.air_date_lte = lte.map(|d| d.to_string().into());
+        self
+    }
+
+    pub fn with_vote_average(mut self, range: impl InclusiveRange<f64>) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.vote_average_gte = gte;
+        self.parameters.vote_average_lte = lte;
+        self
+    }
+
+    pub fn with_vote_count(mut self, range: impl InclusiveRange<u32>) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.vote_count_gte = gte.map(Into::into);
+        self.parameters.vote_count_lte = lte.map(Into::into);
+        self
+    }
+
+    /// Episode runtime in minutes.
+    pub fn with_runtime(mut self, range: impl InclusiveRange<u32>) -> Self {
+        let (gte, lte) = range.into_bounds();
+        self.parameters.with_runtime_gte = gte.map(Into::into);
+        self.parameters.with_runtime_lte = lte.map(Into::into);
+        self
+    }
+
+    pub fn with_status(mut self, status: FilterList<SeriesStatus>) -> Self {
+        self.parameters.with_status = Some(status.to_string().into());
+        self
+    }
+
+    pub fn with_type(mut self, r#type: FilterList<SeriesType>) -> Self {
+        self.parameters.with_type = Some(r#type.to_string().into());
+        self
+    }
+
+    pub fn with_networks(mut self, networks: FilterList<NetworkId>) -> Self {
+        self.parameters.with_networks = Some(networks.to_string().into());
+        self
+    }
+
+    pub fn with_genres(mut self, genres: FilterList<GenreId>) -> Self {
+        self.parameters.with_genres = Some(genres.to_string().into());
+        self
+    }
+
+    pub fn without_genres<T: Into<GenreId>>(mut self, genres: impl IntoIterator<Item = T>) -> Self {
+        self.parameters.without_genres =
+            Some(comma_separated(genres.into_iter().map(Into::into)).into());
+        self
+    }
+
+    pub fn with_companies(mut self, companies: FilterList<CompanyId>) -> Self {
+        self.parameters.with_companies = Some(companies.to_string().into());
+        self
+    }
+
+    pub fn without_companies<T: Into<CompanyId>>(
+        mut self,
+        companies: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.parameters.without_companies =
+            Some(comma_separated(companies.into_iter().map(Into::into)).into());
+        self
+    }
+
+    pub fn with_keywords(mut self, keywords: FilterList<KeywordId>) -> Self {
+        self.parameters.with_keywords = Some(keywords.to_string().into());
+        self
+    }
+
+    pub fn without_keywords<T: Into<KeywordId>>(
+        mut self,
+        keywords: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.parameters.without_keywords =
+            Some(comma_separated(keywords.into_iter().map(Into::into)).into());
+        self
+    }
+
+    pub fn with_origin_country(mut self, country: Region) -> Self {
+        self.parameters.with_origin_country = Some(country.to_string().into());
+        self
+    }
+
+    pub fn with_original_language(mut self, language: Language) -> Self {
+        self.parameters.with_original_language = Some(language.to_string().into());
+        self
+    }
+
+    /// Region used by the watch provider and monetization filters.
+    pub fn with_watch_region(mut self, region: Region) -> Self {
+        self.parameters.watch_region = Some(region.to_string().into());
+        self
+    }
+
+    pub fn with_watch_providers(mut self, providers: FilterList<WatchProviderId>) -> Self {
+        self.parameters.with_watch_providers = Some(providers.to_string().into());
+        self
+    }
+
+    pub fn without_watch_providers<T: Into<WatchProviderId>>(
+        mut self,
+        providers: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.parameters.without_watch_providers =
+            Some(comma_separated(providers.into_iter().map(Into::into)).into());
+        self
+    }
+
+    pub fn with_watch_monetization_types(
+        mut self,
+        monetization_types: FilterList<MonetizationType>,
+    ) -> Self {
+        self.parameters.with_watch_monetization_types = Some(monetization_types.to_string().into());
+        self
+    }
+
+    pub fn search(self) -> Result<DiscoverTvResponse<'a>, Error> {
+        PagedResponse::load(self)
+    }
+}
+
+impl PagedBuilder for DiscoverTvBuilder<'_> {
+    type Page = DiscoverTvResponse200;
+
+    fn fetch_page(&self) -> Result<Self::Page, Error> {
+        Ok(
+            tmdb_easy_raw::parametrized_functions::discover_tv_with_parameter(
+                &self.client.client,
+                self.client.api_key.as_ref(),
+                self.parameters.clone(),
+            )?,
+        )
+    }
+
+    fn set_page(&mut self, page: i64) {
+        self.parameters.page = Some(page);
+    }
+}
+
+#[test]
+fn discover_tv_query_values() {
+    let client = TmdbClient::new("offline");
+    let builder = DiscoverTvBuilder::new(&client)
+        .with_sort_by(TvSortBy::FirstAirDate, SortOrder::Ascending)
+        .with_status(FilterList::any([
+            SeriesStatus::ReturningSeries,
+            SeriesStatus::Ended,
+        ]))
+        .with_type(FilterList::all([SeriesType::Scripted]))
+        .with_networks(FilterList::any([213u64, 49]))
+        .with_first_air_date(Date::new(2020, 1, 1)..=Date::new(2020, 12, 31))
+        .with_timezone("America/New_York".parse().unwrap())
+        .with_origin_country("us".parse().unwrap())
+        .with_page(2);
+    let parameters = &builder.parameters;
+    assert_eq!(parameters.sort_by.as_deref(), Some("first_air_date.asc"));
+    assert_eq!(parameters.with_status.as_deref(), Some("0|3"));
+    assert_eq!(parameters.with_type.as_deref(), Some("4"));
+    // Documented as a single integer, but tmdb accepts a list.
+    assert_eq!(parameters.with_networks.as_deref(), Some("213|49"));
+    assert_eq!(parameters.first_air_date_gte.as_deref(), Some("2020-01-01"));
+    assert_eq!(parameters.first_air_date_lte.as_deref(), Some("2020-12-31"));
+    assert_eq!(parameters.timezone.as_deref(), Some("America/New_York"));
+    assert_eq!(parameters.with_origin_country.as_deref(), Some("US"));
+    assert_eq!(parameters.page, Some(2));
+}
+
+#[test]
+fn discover_tv_page_with_fractional_rating() {
+    use crate::{MovieOrTvLike, page::ResultPage};
+
+    let page: DiscoverTvResponse200 = serde_json::from_str(
+        r#"{"page": 1, "total_pages": 5, "total_results": 100, "results": [
+            {"id": 1396, "name": "Breaking Bad", "vote_average": 8.9, "vote_count": 13000,
+             "first_air_date": "2008-01-20", "genre_ids": [18, 80], "origin_country": ["US"],
+             "poster_path": "/ztkUQFLlC19CCMYHW9o1zWhJRNq.jpg"}
+        ]}"#,
+    )
+    .unwrap();
+    assert_eq!(page.total_pages(), 5);
+    let series = &page.results()[0];
+    assert_eq!(series.vote_average, 8.9);
+    assert_eq!(series.id(), 1396);
+    assert_eq!(series.name, "Breaking Bad");
+}
@@ -0,0 +1,287 @@
+//! Alternative orderings of the episodes of a tv series, like the DVD order
+//! or absolute numbering for anime.
+
+use std::{fmt::Display, str::FromStr};
+
+use tmdb_easy_raw::types::{TvEpisodeGroupDetailsResponse200, TvSeriesEpisodeGroupsResponse200};
+
+use crate::{client::TmdbClient, error::Error};
+
+/// The kinds of orderings tmdb knows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EpisodeGroupType {
+    OriginalAirDate = 1,
+    Absolute = 2,
+    Dvd = 3,
+    Digital = 4,
+    StoryArc = 5,
+    Production = 6,
+    Tv = 7,
+}
+
+impl EpisodeGroupType {
+    /// The group type for the number tmdb uses, e.g. `3` for DVD.
+    pub fn from_number(number: i64) -> Option<Self> {
+        Some(match number {
+            1 => EpisodeGroupType::OriginalAirDate,
+            2 => EpisodeGroupType::Absolute,
+            3 => EpisodeGroupType::Dvd,
+            4 => EpisodeGroupType::Digital,
+            5 => EpisodeGroupType::StoryArc,
+            6 => EpisodeGroupType::Production,
+            7 => EpisodeGroupType::Tv,
+            _ => return None,
+        })
+    }
+}
+
+/// An episode by its aired season and episode number, formatted as `S01E02`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EpisodeNumber {
+    pub season: u32,
+    pub episode: u32,
+}
+
+impl EpisodeNumber {
+    pub fn new(season: u32, episode: u32) -> Self {
+        Self { season, episode }
+    }
+}
+
+impl Display for EpisodeNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "S{:02}E{:02}", self.season, self.episode)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid episode number, expected SxxEyy: {0:?}")]
+pub struct InvalidEpisodeNumber(pub String);
+
+impl FromStr for EpisodeNumber {
+    type Err = InvalidEpisodeNumber;
+
+    /// Accepts upper and lower case, e.g. `S01E02` and `s1e2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidEpisodeNumber(s.to_string());
+        let rest = s
+            .strip_prefix(['S', 's'])
+            .ok_or_else(invalid)?
+            .to_ascii_lowercase();
+        let (season, episode) = rest.split_once('e').ok_or_else(invalid)?;
+        Ok(Self {
+            season: season.parse().map_err(|_| invalid())?,
+            episode: episode.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// An episode group of a series, without its episodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpisodeGroupSummary {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    /// `None` for types unknown to this crate.
+    pub group_type: Option<EpisodeGroupType>,
+    pub episode_count: u32,
+    pub group_count: u32,
+    /// The network the ordering comes from, empty if unknown.
+    pub network: String,
+}
+
+impl EpisodeGroupSummary {
+    pub(crate) fn fetch(client: &TmdbClient, series_id: u64) -> Result<Vec<Self>, Error> {
+        let response: TvSeriesEpisodeGroupsResponse200 =
+            tmdb_easy_raw::parametrized_functions::tv_series_episode_groups(
+                &client.client,
+                &client.api_key,
+                series_id as _,
+            )?;
+        Ok(response
+            .results
+            .into_iter()
+            .map(|group| Self {
+                id: group.id,
+                name: group.name,
+                description: group.description,
+                group_type: EpisodeGroupType::from_number(group.r#type),
+                episode_count: group.episode_count as _,
+                group_count: group.group_count as _,
+                network: group.network.name,
+            })
+            .collect())
+    }
+
+    pub fn details(&self, client: &TmdbClient) -> Result<EpisodeGroup, Error> {
+        EpisodeGroup::fetch(client, &self.id)
+    }
+}
+
+/// An episode inside an episode group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupedEpisode {
+    pub id: u64,
+    pub name: String,
+    pub air_date: String,
+    /// The number the episode was aired with.
+    pub aired: EpisodeNumber,
+}
+
+/// A part of an episode group, comparable to a season.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpisodeSubgroup {
+    pub id: String,
+    pub name: String,
+    /// The episodes in the order of the group.
+    pub episodes: Vec<GroupedEpisode>,
+}
+
+/// The position of an episode in an episode group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GroupPosition {
+    /// Index of the subgroup, starting at `0`.
+    pub group: usize,
+    /// Number of the episode in its subgroup, starting at `1`.
+    pub episode: u32,
+    /// Number of the episode in the whole episode group, starting at `1`.
+    pub absolute: u32,
+}
+
+/// An alternative ordering of the episodes of a series.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpisodeGroup {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub group_type: Option<EpisodeGroupType>,
+    /// The subgroups in order.
+    pub groups: Vec<EpisodeSubgroup>,
+}
+
+impl From<TvEpisodeGroupDetailsResponse200> for EpisodeGroup {
+    fn from(value: TvEpisodeGroupDetailsResponse200) -> Self {
+        let mut groups = value.groups;
+        groups.sort_by_key(|group| group.order);
+        Self {
+            id: value.id,
+            name: value.name,
+            description: value.description,
+            group_type: EpisodeGroupType::from_number(value.r#type),
+            groups: groups
+                .into_iter()
+                .map(|group| {
+                    let mut episodes = group.episodes;
+                    episodes.sort_by_key(|episode| episode.order);
+                    EpisodeSubgroup {
+                        id: group.id,
+                        name: group.name,
+                        episodes: episodes
+                            .into_iter()
+                            .map(|episode| GroupedEpisode {
+                                id: episode.id as _,
+                                name: episode.name,
+                                air_date: episode.air_date,
+                                aired: EpisodeNumber::new(
+                                    episode.season_number as _,
+                                    episode.episode_number as _,
+                                ),
+                            })
+                            .collect(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl EpisodeGroup {
+    pub(crate) fn fetch(client: &TmdbClient, id: &str) -> Result<Self, Error> {
+        Ok(
+            tmdb_easy_raw::parametrized_functions::tv_episode_group_details(
+                &client.client,
+                &client.api_key,
+                id,
+            )?
+            .into(),
+        )
+    }
+
+    /// All episodes in the order of the group, with their positions.
+    pub fn episodes(&self) -> impl Iterator<Item = (GroupPosition, &GroupedEpisode)> {
+        self.groups
+            .iter()
+            .enumerate()
+            .flat_map(|(group, subgroup)| {
+                subgroup
+                    .episodes
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, episode)| (group, index as u32 + 1, episode))
+            })
+            .zip(1..)
+            .map(|((group, episode, grouped), absolute)| {
+                (
+                    GroupPosition {
+                        group,
+                        episode,
+                        absolute,
+                    },
+                    grouped,
+                )
+            })
+    }
+
+    /// Where an aired episode is placed in this ordering.
+    pub fn position(&self, aired: EpisodeNumber) -> Option<GroupPosition> {
+        self.episodes()
+            .find(|(_, episode)| episode.aired == aired)
+            .map(|(position, _)| position)
+    }
+
+    /// The aired number of the `episode`th episode in the subgroup `group`.
+    pub fn aired(&self, group: usize, episode: u32) -> Option<EpisodeNumber> {
+        let index = episode.checked_sub(1)? as usize;
+        Some(self.groups.get(group)?.episodes.get(index)?.aired)
+    }
+
+    /// The aired number of the episode with the given absolute number.
+    pub fn aired_from_absolute(&self, absolute: u32) -> Option<EpisodeNumber> {
+        self.episodes()
+            .find(|(position, _)| position.absolute == absolute)
+            .map(|(_, episode)| episode.aired)
+    }
+}
+
+#[test]
+fn episode_group_numbering() {
+    let group: TvEpisodeGroupDetailsResponse200 = serde_json::from_str(
+        r#"{"id": "5b11ba820e0a265847002c6e", "name": "DVD Order", "type": 3, "groups": [
+            {"id": "b", "name": "Volume 2", "order": 1, "episodes": [
+                {"id": 4, "season_number": 1, "episode_number": 3, "order": 0}
+            ]},
+            {"id": "a", "name": "Volume 1", "order": 0, "episodes": [
+                {"id": 2, "season_number": 1, "episode_number": 2, "order": 1},
+                {"id": 1, "season_number": 1, "episode_number": 1, "order": 0},
+                {"id": 3, "season_number": 0, "episode_number": 1, "order": 2}
+            ]}
+        ]}"#,
+    )
+    .unwrap();
+    let group = EpisodeGroup::from(group);
+    assert_eq!(group.group_type, Some(EpisodeGroupType::Dvd));
+    assert_eq!(group.groups[0].name, "Volume 1");
+    let s01e03: EpisodeNumber = "S01E03".parse().unwrap();
+    assert_eq!(
+        group.position(s01e03),
+        Some(GroupPosition {
+            group: 1,
+            episode: 1,
+            absolute: 4
+        })
+    );
+    assert_eq!(group.aired(0, 3), Some(EpisodeNumber::new(0, 1)));
+    assert_eq!(group.aired_from_absolute(2).unwrap().to_string(), "S01E02");
+    assert_eq!(group.aired(2, 1), None);
+    assert!("1x02".parse::<EpisodeNumber>().is_err());
+}
@@ -2,6 +2,8 @@ use std::fmt::Display;
 
 use reqwest::StatusCode;
 
+use crate::date::Date;
+
 #[derive(Debug)]
 pub struct Error {
     pub source: &'static str,
@@ -30,6 +32,32 @@ pub enum ErrorKind {
     DecodingError,
     #[error("Failed creating a valid reqwest Request: {0}")]
     RequestCreationError(reqwest::Error),
+    /// tmdb understood the request, but refused it, e.g. because of a wrong
+    /// password or an unapproved request token.
+    #[error("Request was rejected with StatusCode {0}: {1}")]
+    RequestRejected(StatusCode, String),
+    /// A section requested with `append_to_response` was missing in the
+    /// answer.
+    #[error("The answer did not contain the requested {0}")]
+    MissingSection(&'static str),
+    /// tmdb only lists changes in windows of up to 14 days, that do not end
+    /// before they start.
+    #[error("Invalid window from {0} to {1}, expected at most 14 days")]
+    InvalidWindow(Date, Date),
+    #[cfg(feature = "mirror")]
+    #[error("Problem accessing the local mirror: {0}")]
+    StorageError(rusqlite::Error),
+}
+
+impl ErrorKind {
+    /// Status code `7` is tmdb's code for an invalid api key.
+    pub(crate) fn from_status(status: StatusCode, status_code: i64, message: String) -> Self {
+        if status == StatusCode::UNAUTHORIZED && status_code == 7 {
+            Self::AuthenticationError
+        } else {
+            Self::RequestRejected(status, message)
+        }
+    }
 }
 
 impl From<tmdb_easy_raw::Error> for Error {
@@ -0,0 +1,311 @@
+//! Looks up tmdb entries by their ids on other sites like IMDb or TheTVDB,
+//! and the other way round.
+
+use std::{borrow::Cow, fmt::Display};
+
+use tmdb_easy_raw::types::{
+    FindByIdResponse200MovieResults, SearchPersonResponse200Results, SearchTvResponse200Results,
+    TvSeasonDetailsResponse200Episodes, TvSeriesDetailsResponse200Seasons,
+};
+
+use crate::{
+    MediaType, MovieOrTvLike,
+    client::{TmdbClient, path_with_segment},
+    error::Error,
+    impl_movie_or_tv_like,
+    localization::Locale,
+};
+
+/// The sites, that can be searched with [`TmdbClient::find`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExternalSource {
+    /// E.g. `tt0137523` for movies, series and episodes or `nm0000093` for
+    /// people.
+    Imdb,
+    Tvdb,
+    Wikidata,
+    Facebook,
+    Instagram,
+    Twitter,
+    TikTok,
+    YouTube,
+}
+
+impl Display for ExternalSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExternalSource::Imdb => write!(f, "imdb_id"),
+            ExternalSource::Tvdb => write!(f, "tvdb_id"),
+            ExternalSource::Wikidata => write!(f, "wikidata_id"),
+            ExternalSource::Facebook => write!(f, "facebook_id"),
+            ExternalSource::Instagram => write!(f, "instagram_id"),
+            ExternalSource::Twitter => write!(f, "twitter_id"),
+            ExternalSource::TikTok => write!(f, "tiktok_id"),
+            ExternalSource::YouTube => write!(f, "youtube_id"),
+        }
+    }
+}
+
+/// A season found with [`TmdbClient::find`].
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct FoundSeason {
+    #[serde(flatten)]
+    pub season: TvSeriesDetailsResponse200Seasons,
+    /// The id of the tv series.
+    #[serde(default)]
+    pub show_id: u64,
+}
+
+/// A match of [`TmdbClient::find`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FindResult {
+    Movie(FindByIdResponse200MovieResults),
+    Tv(SearchTvResponse200Results),
+    Person(SearchPersonResponse200Results),
+    Season(FoundSeason),
+    /// The episode contains the id of its series as `show_id`.
+    Episode(TvSeasonDetailsResponse200Episodes),
+}
+
+impl FindResult {
+    pub fn id(&self) -> u64 {
+        match self {
+            FindResult::Movie(it) => it.id as _,
+            FindResult::Tv(it) => it.id as _,
+            FindResult::Person(it) => it.id as _,
+            FindResult::Season(it) => it.season.id as _,
+            FindResult::Episode(it) => it.id as _,
+        }
+    }
+
+    /// Returns the movie or tv series, so the poster helpers can be used.
+    pub fn as_movie_or_tv(&self) -> Option<&dyn MovieOrTvLike> {
+        match self {
+            FindResult::Movie(it) => Some(it),
+            FindResult::Tv(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+
+impl_movie_or_tv_like!(FindByIdResponse200MovieResults, MediaType::Movie);
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+struct FindResponse {
+    movie_results: Vec<FindByIdResponse200MovieResults>,
+    tv_results: Vec<SearchTvResponse200Results>,
+    person_results: Vec<SearchPersonResponse200Results>,
+    tv_season_results: Vec<FoundSeason>,
+    tv_episode_results: Vec<TvSeasonDetailsResponse200Episodes>,
+}
+
+impl From<FindResponse> for Vec<FindResult> {
+    fn from(value: FindResponse) -> Self {
+        let movies = value.movie_results.into_iter().map(FindResult::Movie);
+        let tv = value.tv_results.into_iter().map(FindResult::Tv);
+        let people = value.person_results.into_iter().map(FindResult::Person);
+        let seasons = value.tv_season_results.into_iter().map(FindResult::Season);
+        let episodes = value
+            .tv_episode_results
+            .into_iter()
+            .map(FindResult::Episode);
+        movies
+            .chain(tv)
+            .chain(people)
+            .chain(seasons)
+            .chain(episodes)
+            .collect()
+    }
+}
+
+/// Finds everything on tmdb with an id on another site.
+pub struct FindBuilder<'a> {
+    client: &'a TmdbClient,
+    external_id: Cow<'a, str>,
+    source: ExternalSource,
+    language: Option<Cow<'a, str>>,
+}
+
+impl<'a> FindBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, external_id: Cow<'a, str>, source: ExternalSource) -> Self {
+        Self {
+            client,
+            external_id,
+            source,
+            language: client.language.clone(),
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    /// The matches, movies first, then tv series, people, seasons and
+    /// episodes. Usually there is at most one.
+    pub fn load(self) -> Result<Vec<FindResult>, Error> {
+        let mut query = vec![("external_source", self.source.to_string())];
+        if let Some(language) = self.language {
+            query.push(("language", language.into_owned()));
+        }
+        let response: FindResponse = self.client.get_json(
+            "https://api.themoviedb.org/3/find/{external_id}",
+            &path_with_segment("/3/find", self.external_id.trim()),
+            &query,
+        )?;
+        Ok(response.into())
+    }
+}
+
+/// The ids of a movie, tv series, season, episode or person on other sites.
+/// Ids, that tmdb does not know, are `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExternalIds {
+    pub imdb_id: Option<String>,
+    pub tvdb_id: Option<u64>,
+    pub wikidata_id: Option<String>,
+    pub facebook_id: Option<String>,
+    pub instagram_id: Option<String>,
+    pub twitter_id: Option<String>,
+    pub tiktok_id: Option<String>,
+    pub youtube_id: Option<String>,
+    pub freebase_id: Option<String>,
+    pub freebase_mid: Option<String>,
+    pub tvrage_id: Option<u64>,
+}
+
+impl ExternalIds {
+    /// The id on `source`, in the form [`TmdbClient::find`] accepts it.
+    pub fn get(&self, source: ExternalSource) -> Option<String> {
+        match source {
+            ExternalSource::Imdb => self.imdb_id.clone(),
+            ExternalSource::Tvdb => self.tvdb_id.map(|id| id.to_string()),
+            ExternalSource::Wikidata => self.wikidata_id.clone(),
+            ExternalSource::Facebook => self.facebook_id.clone(),
+            ExternalSource::Instagram => self.instagram_id.clone(),
+            ExternalSource::Twitter => self.twitter_id.clone(),
+            ExternalSource::TikTok => self.tiktok_id.clone(),
+            ExternalSource::YouTube => self.youtube_id.clone(),
+        }
+    }
+}
+
+fn non_empty(id: String) -> Option<String> {
+    (!id.trim().is_empty()).then_some(id)
+}
+
+fn non_zero(id: i64) -> Option<u64> {
+    (id > 0).then_some(id as _)
+}
+
+/// All ids any of the external id endpoints returns. The generated types
+/// miss some of them, e.g. the wikidata id of movies.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+struct RawExternalIds {
+    imdb_id: Option<String>,
+    tvdb_id: Option<i64>,
+    wikidata_id: Option<String>,
+    facebook_id: Option<String>,
+    instagram_id: Option<String>,
+    twitter_id: Option<String>,
+    tiktok_id: Option<String>,
+    youtube_id: Option<String>,
+    freebase_id: Option<String>,
+    freebase_mid: Option<String>,
+    tvrage_id: Option<i64>,
+}
+
+impl From<RawExternalIds> for ExternalIds {
+    fn from(value: RawExternalIds) -> Self {
+        Self {
+            imdb_id: value.imdb_id.and_then(non_empty),
+            tvdb_id: value.tvdb_id.and_then(non_zero),
+            wikidata_id: value.wikidata_id.and_then(non_empty),
+            facebook_id: value.facebook_id.and_then(non_empty),
+            instagram_id: value.instagram_id.and_then(non_empty),
+            twitter_id: value.twitter_id.and_then(non_empty),
+            tiktok_id: value.tiktok_id.and_then(non_empty),
+            youtube_id: value.youtube_id.and_then(non_empty),
+            freebase_id: value.freebase_id.and_then(non_empty),
+            freebase_mid: value.freebase_mid.and_then(non_empty),
+            tvrage_id: value.tvrage_id.and_then(non_zero),
+        }
+    }
+}
+
+impl ExternalIds {
+    pub(crate) fn fetch(
+        client: &TmdbClient,
+        source: &'static str,
+        path: &str,
+    ) -> Result<Self, Error> {
+        let response: RawExternalIds = client.get_json(source, path, &[])?;
+        Ok(response.into())
+    }
+}
+
+#[test]
+fn find_results_and_external_ids() {
+    let response: FindResponse = serde_json::from_str(
+        r#"{
+            "movie_results": [],
+            "person_results": [],
+            "tv_results": [],
+            "tv_episode_results": [{"id": 63056, "name": "Winter Is Coming", "season_number": 1, "episode_number": 1, "show_id": 1399}],
+            "tv_season_results": [{"id": 3624, "name": "Season 1", "season_number": 1, "show_id": 1399, "vote_average": 8.3}]
+        }"#,
+    )
+    .unwrap();
+    let results = Vec::<FindResult>::from(response);
+    let [FindResult::Season(season), FindResult::Episode(episode)] = results.as_slice() else {
+        panic!("expected a season and an episode, got {results:?}");
+    };
+    assert_eq!(season.show_id, 1399);
+    assert_eq!(season.season.name, "Season 1");
+    assert_eq!(season.season.vote_average, 8.3);
+    assert_eq!(episode.show_id, 1399);
+    assert!(results[1].as_movie_or_tv().is_none());
+
+    assert_eq!(
+        path_with_segment("/3/find", "tt0944947"),
+        "/3/find/tt0944947"
+    );
+    assert_eq!(
+        path_with_segment("/3/find", "../movie/550?x=1#y"),
+        "/3/find/..%2Fmovie%2F550%3Fx=1%23y"
+    );
+
+    let ids: RawExternalIds = serde_json::from_str(
+        r#"{"id": 1399, "imdb_id": "tt0944947", "tvdb_id": 121361, "tvrage_id": null, "twitter_id": ""}"#,
+    )
+    .unwrap();
+    let ids = ExternalIds::from(ids);
+    assert_eq!(ids.get(ExternalSource::Imdb).as_deref(), Some("tt0944947"));
+    assert_eq!(ids.get(ExternalSource::Tvdb).as_deref(), Some("121361"));
+    assert_eq!(ids.twitter_id, None);
+    assert_eq!(ids.tvrage_id, None);
+
+    let movie: RawExternalIds = serde_json::from_str(
+        r#"{"id": 550, "imdb_id": "tt0137523", "wikidata_id": "Q190050", "facebook_id": "FightClub",
+            "instagram_id": "fightclub", "twitter_id": "fightclub"}"#,
+    )
+    .unwrap();
+    let movie = ExternalIds::from(movie);
+    assert_eq!(movie.wikidata_id.as_deref(), Some("Q190050"));
+    assert_eq!(
+        movie.get(ExternalSource::Instagram).as_deref(),
+        Some("fightclub")
+    );
+    assert_eq!(movie.get(ExternalSource::YouTube), None);
+
+    let person: RawExternalIds =
+        serde_json::from_str(r#"{"id": 287, "youtube_id": "UCbradpitt"}"#).unwrap();
+    assert_eq!(
+        ExternalIds::from(person)
+            .get(ExternalSource::YouTube)
+            .as_deref(),
+        Some("UCbradpitt")
+    );
+}
@@ -0,0 +1,188 @@
+use tmdb_easy_raw::{
+    parameter_types::{GenreMovieListParameter, GenreTvListParameter},
+    types::{
+        AccountFavoriteTvResponse200Results, AccountGetFavoritesResponse200Results,
+        AccountRatedMoviesResponse200Results, AccountRatedTvResponse200Results,
+        AccountWatchlistMoviesResponse200Results, AccountWatchlistTvResponse200Results,
+        CollectionDetailsResponse200Parts, DiscoverMovieResponse200Results,
+        DiscoverTvResponse200Results, MovieNowPlayingListResponse200Results,
+        MoviePopularListResponse200Results, MovieSimilarResponse200Results,
+        MovieTopRatedListResponse200Results, MovieUpcomingListResponse200Results,
+        SearchMovieResponse200Results, SearchTvResponse200Results,
+        TrendingMoviesResponse200Results, TrendingTvResponse200Results,
+        TvSeriesAiringTodayListResponse200Results, TvSeriesOnTheAirListResponse200Results,
+        TvSeriesPopularListResponse200Results, TvSeriesSimilarResponse200Results,
+        TvSeriesTopRatedListResponse200Results,
+    },
+};
+
+use crate::{client::TmdbClient, error::Error, id::GenreId};
+
+/// Movies and tv series have separate genre lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GenreKind {
+    Movie,
+    Tv,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Genre {
+    pub id: GenreId,
+    pub name: String,
+}
+
+/// All genres of one kind in one language.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GenreList {
+    pub genres: Vec<Genre>,
+}
+
+impl GenreList {
+    pub(crate) fn fetch(
+        client: &TmdbClient,
+        kind: GenreKind,
+        language: Option<&str>,
+    ) -> Result<Self, Error> {
+        let genres = match kind {
+            GenreKind::Movie => {
+                tmdb_easy_raw::parametrized_functions::genre_movie_list_with_parameter(
+                    &client.client,
+                    &client.api_key,
+                    GenreMovieListParameter {
+                        language: language.map(Into::into),
+                    },
+                )?
+                .genres
+                .into_iter()
+                .map(|genre| (genre.id, genre.name))
+                .collect::<Vec<_>>()
+            }
+            GenreKind::Tv => tmdb_easy_raw::parametrized_functions::genre_tv_list_with_parameter(
+                &client.client,
+                &client.api_key,
+                GenreTvListParameter {
+                    language: language.map(Into::into),
+                },
+            )?
+            .genres
+            .into_iter()
+            .map(|genre| (genre.id, genre.name))
+            .collect(),
+        };
+        Ok(Self {
+            genres: genres
+                .into_iter()
+                .map(|(id, name)| Genre {
+                    id: GenreId(id as _),
+                    name,
+                })
+                .collect(),
+        })
+    }
+
+    pub fn get(&self, id: GenreId) -> Option<&Genre> {
+        self.genres.iter().find(|genre| genre.id == id)
+    }
+
+    pub fn name(&self, id: GenreId) -> Option<&str> {
+        self.get(id).map(|genre| genre.name.as_str())
+    }
+
+    /// Looks up a genre by its name, ignoring case. The name has to be in the
+    /// language of this list.
+    pub fn id(&self, name: &str) -> Option<GenreId> {
+        self.genres
+            .iter()
+            .find(|genre| genre.name.to_lowercase() == name.to_lowercase())
+            .map(|genre| genre.id)
+    }
+
+    /// Resolves the ids, unknown ids are skipped.
+    pub fn resolve<'a>(&'a self, ids: &'a [i64]) -> impl Iterator<Item = &'a Genre> + 'a {
+        ids.iter().filter_map(|id| self.get(GenreId(*id as _)))
+    }
+}
+
+/// Results, that only carry the ids of their genres.
+pub trait GenreIdsLike {
+    const GENRE_KIND: GenreKind;
+
+    fn genre_ids(&self) -> &[i64];
+}
+
+pub trait GenreIdsLikeExt: GenreIdsLike {
+    /// Resolves the genres in the default language of the client. The genre
+    /// list is only downloaded once per language.
+    fn genres(&self, client: &mut TmdbClient) -> Result<Vec<Genre>, Error> {
+        let list = client.genres(Self::GENRE_KIND)?;
+        Ok(list.resolve(self.genre_ids()).cloned().collect())
+    }
+}
+
+impl<T: GenreIdsLike + ?Sized> GenreIdsLikeExt for T {}
+
+macro_rules! genre_ids_like {
+    ($kind:ident: $($result:ty),* $(,)?) => {
+        $(
+            impl GenreIdsLike for $result {
+                const GENRE_KIND: GenreKind = GenreKind::$kind;
+
+                fn genre_ids(&self) -> &[i64] {
+                    &self.genre_ids
+                }
+            }
+        )*
+    };
+}
+
+genre_ids_like!(
+    Movie: SearchMovieResponse200Results,
+    DiscoverMovieResponse200Results,
+    TrendingMoviesResponse200Results,
+    MoviePopularListResponse200Results,
+    MovieTopRatedListResponse200Results,
+    MovieUpcomingListResponse200Results,
+    MovieNowPlayingListResponse200Results,
+    CollectionDetailsResponse200Parts,
+    MovieSimilarResponse200Results,
+    AccountGetFavoritesResponse200Results,
+    AccountWatchlistMoviesResponse200Results,
+    AccountRatedMoviesResponse200Results,
+);
+genre_ids_like!(
+    Tv: SearchTvResponse200Results,
+    DiscoverTvResponse200Results,
+    TrendingTvResponse200Results,
+    TvSeriesPopularListResponse200Results,
+    TvSeriesTopRatedListResponse200Results,
+    TvSeriesAiringTodayListResponse200Results,
+    TvSeriesOnTheAirListResponse200Results,
+    TvSeriesSimilarResponse200Results,
+    AccountFavoriteTvResponse200Results,
+    AccountWatchlistTvResponse200Results,
+    AccountRatedTvResponse200Results,
+);
+
+#[test]
+fn genre_list_lookups() {
+    let list = GenreList {
+        genres: vec![
+            Genre {
+                id: GenreId(28),
+                name: "Action".into(),
+            },
+            Genre {
+                id: GenreId(35),
+                name: "Comedy".into(),
+            },
+        ],
+    };
+    assert_eq!(list.name(GenreId(35)), Some("Comedy"));
+    assert_eq!(list.id("action"), Some(GenreId(28)));
+    assert_eq!(list.id("Drama"), None);
+    let names: Vec<_> = list
+        .resolve(&[35, 99, 28])
+        .map(|g| g.name.as_str())
+        .collect();
+    assert_eq!(names, ["Comedy", "Action"]);
+}
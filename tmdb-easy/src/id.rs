@@ -0,0 +1,49 @@
+//! Typed ids for the different kinds of entities tmdb knows about. They all
+//! wrap the plain numeric id, but make sure a genre id can not be passed where
+//! a person id is expected.
+
+use std::fmt::Display;
+
+macro_rules! id_type {
+    ($($(#[$meta:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Deserialize)]
+            #[serde(transparent)]
+            pub struct $name(pub u64);
+
+            impl From<u64> for $name {
+                fn from(value: u64) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl From<$name> for u64 {
+                fn from(value: $name) -> Self {
+                    value.0
+                }
+            }
+
+            impl Display for $name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+        )*
+    };
+}
+
+id_type!(
+    /// Id of a movie or tv genre.
+    GenreId,
+    /// Id of a person, used for cast, crew and people filters.
+    PersonId,
+    /// Id of a production company.
+    CompanyId,
+    /// Id of a keyword.
+    KeywordId,
+    /// Id of a streaming, rental or purchase provider.
+    WatchProviderId,
+    /// Id of a tv network.
+    NetworkId,
+);
@@ -0,0 +1,110 @@
+use tmdb_easy_raw::types::{
+    KeywordDetailsResponse200, MovieKeywordsResponse200, TvSeriesKeywordsResponse200,
+};
+
+use crate::{
+    client::TmdbClient,
+    discover::{FilterList, movie::DiscoverMovieBuilder, tv::DiscoverTvBuilder},
+    error::Error,
+    id::KeywordId,
+};
+
+/// A keyword, like `based on novel or book` or `time travel`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Keyword {
+    pub id: KeywordId,
+    pub name: String,
+}
+
+impl From<KeywordDetailsResponse200> for Keyword {
+    fn from(value: KeywordDetailsResponse200) -> Self {
+        Self {
+            id: KeywordId(value.id as _),
+            name: value.name,
+        }
+    }
+}
+
+impl Keyword {
+    pub(crate) fn fetch(client: &TmdbClient, id: KeywordId) -> Result<Self, Error> {
+        Ok(tmdb_easy_raw::parametrized_functions::keyword_details(
+            &client.client,
+            &client.api_key,
+            id.0 as _,
+        )?
+        .into())
+    }
+
+    /// Prepares discovering movies with this keyword.
+    pub fn discover_movies<'a>(&self, client: &'a TmdbClient) -> DiscoverMovieBuilder<'a> {
+        client
+            .discover_movies()
+            .with_keywords(FilterList::all([self.id]))
+    }
+
+    /// Prepares discovering tv series with this keyword.
+    pub fn discover_tv<'a>(&self, client: &'a TmdbClient) -> DiscoverTvBuilder<'a> {
+        client
+            .discover_tv()
+            .with_keywords(FilterList::all([self.id]))
+    }
+}
+
+/// The keywords of a movie or tv series.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Keywords {
+    pub keywords: Vec<Keyword>,
+}
+
+impl From<MovieKeywordsResponse200> for Keywords {
+    fn from(value: MovieKeywordsResponse200) -> Self {
+        Self {
+            keywords: value
+                .keywords
+                .into_iter()
+                .map(|keyword| Keyword {
+                    id: KeywordId(keyword.id as _),
+                    name: keyword.name,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<TvSeriesKeywordsResponse200> for Keywords {
+    fn from(value: TvSeriesKeywordsResponse200) -> Self {
+        Self {
+            keywords: value
+                .results
+                .into_iter()
+                .map(|keyword| Keyword {
+                    id: KeywordId(keyword.id as _),
+                    name: keyword.name,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Keywords {
+    /// Case-insensitive lookup by name.
+    pub fn get(&self, name: &str) -> Option<&Keyword> {
+        self.keywords
+            .iter()
+            .find(|keyword| keyword.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Prepares discovering movies, that share all of these keywords.
+    pub fn discover_movies<'a>(&self, client: &'a TmdbClient) -> DiscoverMovieBuilder<'a> {
+        client
+            .discover_movies()
+            .with_keywords(FilterList::all(self.keywords.iter().map(|k| k.id)))
+    }
+
+    /// Prepares discovering tv series, that share all of these keywords.
+    pub fn discover_tv<'a>(&self, client: &'a TmdbClient) -> DiscoverTvBuilder<'a> {
+        client
+            .discover_tv()
+            .with_keywords(FilterList::all(self.keywords.iter().map(|k| k.id)))
+    }
+}
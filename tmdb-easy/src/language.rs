@@ -0,0 +1,75 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::configuration::ReferenceData;
+
+/// A language as an ISO 639-1 code, like `de` or `en`. tmdb also uses a few
+/// codes outside the standard, e.g. `cn` for Cantonese and `xx` for no
+/// language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Deserialize)]
+#[serde(try_from = "String")]
+pub struct Language([u8; 2]);
+
+impl Language {
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).expect("only ascii letters are accepted")
+    }
+
+    /// Whether tmdb knows this language, according to the vendored snapshot
+    /// of the configuration.
+    pub fn is_known(&self) -> bool {
+        self.is_known_in(ReferenceData::snapshot())
+    }
+
+    pub fn is_known_in(&self, data: &ReferenceData) -> bool {
+        data.language(*self).is_some()
+    }
+
+    /// E.g. `German` for `de`, `None` for unknown languages.
+    pub fn english_name(&self) -> Option<&'static str> {
+        ReferenceData::snapshot()
+            .language(*self)
+            .map(|language| language.english_name.as_str())
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid language, expected a two letter language code: {0:?}")]
+pub struct InvalidLanguage(pub String);
+
+impl FromStr for Language {
+    type Err = InvalidLanguage;
+
+    /// Accepts upper and lower case, e.g. `DE` and `de`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            [a, b] if a.is_ascii_alphabetic() && b.is_ascii_alphabetic() => {
+                Ok(Self([a.to_ascii_lowercase(), b.to_ascii_lowercase()]))
+            }
+            _ => Err(InvalidLanguage(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for Language {
+    type Error = InvalidLanguage;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[test]
+fn language_codes() {
+    let language: Language = "DE".parse().unwrap();
+    assert_eq!(language.to_string(), "de");
+    assert_eq!(language.english_name(), Some("German"));
+    assert!(language.is_known());
+    assert!(!"qq".parse::<Language>().unwrap().is_known());
+    assert!("deu".parse::<Language>().is_err());
+}
@@ -1,23 +1,117 @@
+pub mod account;
+pub mod authentication;
+pub mod certification;
+pub mod changes;
+pub mod chart;
 pub mod client;
+pub mod collection;
+pub mod company;
+pub mod configuration;
+pub mod credits;
+pub mod date;
+pub mod discover;
+pub mod episode_group;
 pub mod error;
+pub mod external_ids;
+pub mod genre;
+pub mod id;
+pub mod keyword;
+pub mod language;
+pub mod localization;
+#[cfg(feature = "mirror")]
+pub mod mirror;
 pub mod movie;
+pub mod page;
+pub mod person;
+pub mod recommendation;
+pub mod region;
+pub mod review;
 mod search;
+pub mod timezone;
+pub mod title;
+pub mod trending;
 pub mod tv;
+pub mod video;
+pub mod watch_provider;
 use image::DynamicImage;
 pub use tmdb_easy_raw;
 
-use crate::{client::TmdbClient, error::Error};
+use crate::{
+    client::{ImageKind, TmdbClient},
+    error::Error,
+    recommendation::{RecommendationKind, RecommendationsBuilder},
+    title::TitleSet,
+};
+
+/// Whether an entry is a movie or a tv series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MediaType {
+    Movie,
+    Tv,
+}
 
 pub trait MovieOrTvLike {
     fn id(&self) -> u64;
+    fn media_type(&self) -> MediaType;
     fn poster_path(&self, client: &TmdbClient) -> Result<String, Error>;
 }
 
+/// Implements [`MovieOrTvLike`] for raw types with an `id` and a
+/// `poster_path`, e.g. `impl_movie_or_tv_like!(SearchTvResponse200Results,
+/// MediaType::Tv)`.
+macro_rules! impl_movie_or_tv_like {
+    ($type:ty, $media_type:expr) => {
+        impl $crate::MovieOrTvLike for $type {
+            fn id(&self) -> u64 {
+                self.id as _
+            }
+
+            fn media_type(&self) -> $crate::MediaType {
+                $media_type
+            }
+
+            fn poster_path(
+                &self,
+                _client: &$crate::client::TmdbClient,
+            ) -> Result<String, $crate::error::Error> {
+                Ok(self.poster_path.clone())
+            }
+        }
+    };
+}
+pub(crate) use impl_movie_or_tv_like;
+
 pub trait MovieOrTvLikeExt: MovieOrTvLike {
     fn poster(&self, client: &mut TmdbClient) -> Result<DynamicImage, Error> {
         let poster_path = self.poster_path(client)?;
-        client.resolve_image_path(poster_path)
+        client.resolve_image_path(poster_path, ImageKind::Poster)
+    }
+
+    /// Titles tmdb recommends to people, who liked this one.
+    fn recommendations<'a>(&self, client: &'a TmdbClient) -> RecommendationsBuilder<'a> {
+        RecommendationsBuilder::new(
+            client,
+            self.media_type(),
+            self.id(),
+            RecommendationKind::Recommendations,
+        )
+    }
+
+    /// The original, translated and alternative titles, to match strings
+    /// like release names against.
+    fn titles(&self, client: &TmdbClient) -> Result<TitleSet, Error> {
+        TitleSet::fetch(client, self)
+    }
+
+    /// Titles with similar genres and keywords.
+    fn similar<'a>(&self, client: &'a TmdbClient) -> RecommendationsBuilder<'a> {
+        RecommendationsBuilder::new(
+            client,
+            self.media_type(),
+            self.id(),
+            RecommendationKind::Similar,
+        )
     }
 }
 
-impl<T: MovieOrTvLike> MovieOrTvLikeExt for T {}
+impl<T: MovieOrTvLike + ?Sized> MovieOrTvLikeExt for T {}
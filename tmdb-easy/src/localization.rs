@@ -0,0 +1,365 @@
+//! Picks titles and overviews from the translations of a title, following a
+//! chain of languages like `de-AT,de-DE,en-US,original`.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    client::TmdbClient, configuration::ReferenceData, error::Error, language::Language,
+    region::Region,
+};
+
+/// A language with an optional region, like `de` or `de-AT`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    pub language: Language,
+    pub region: Option<Region>,
+}
+
+impl Locale {
+    pub fn new(language: Language, region: Option<Region>) -> Self {
+        Self { language, region }
+    }
+
+    /// Whether tmdb knows the language and region, according to the vendored
+    /// snapshot of the configuration.
+    pub fn is_known(&self) -> bool {
+        self.is_known_in(ReferenceData::snapshot())
+    }
+
+    pub fn is_known_in(&self, data: &ReferenceData) -> bool {
+        self.language.is_known_in(data) && self.region.is_none_or(|region| region.is_known_in(data))
+    }
+
+    fn matches(&self, translation: &Translation) -> bool {
+        self.language
+            .as_str()
+            .eq_ignore_ascii_case(&translation.iso_639_1)
+            && self
+                .region
+                .is_none_or(|region| region.as_str() == translation.iso_3166_1)
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.region {
+            Some(region) => write!(f, "{}-{region}", self.language),
+            None => write!(f, "{}", self.language),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid locale, expected a language like `de` or `de-AT`: {0:?}")]
+pub struct InvalidLocale(pub String);
+
+impl FromStr for Locale {
+    type Err = InvalidLocale;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidLocale(s.to_string());
+        let (language, region) = match s.split_once('-') {
+            Some((language, region)) => (language, Some(region.parse().map_err(|_| invalid())?)),
+            None => (s, None),
+        };
+        Ok(Self {
+            language: language.parse().map_err(|_| invalid())?,
+            region,
+        })
+    }
+}
+
+impl From<Language> for Locale {
+    fn from(language: Language) -> Self {
+        Self::new(language, None)
+    }
+}
+
+/// One step of a [`FallbackChain`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Fallback {
+    Locale(Locale),
+    /// The original language of the title, and the original title, if it
+    /// has no translated one.
+    Original,
+}
+
+impl FromStr for Fallback {
+    type Err = InvalidLocale;
+
+    /// Parses a locale, or `original`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("original") {
+            Ok(Fallback::Original)
+        } else {
+            s.parse().map(Fallback::Locale)
+        }
+    }
+}
+
+/// The languages to try in order, e.g. `"de-AT,de-DE,en-US,original".parse()`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FallbackChain(pub Vec<Fallback>);
+
+impl FromStr for FallbackChain {
+    type Err = InvalidLocale;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|step| step.trim().parse())
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl FromIterator<Fallback> for FallbackChain {
+    fn from_iter<T: IntoIterator<Item = Fallback>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct TranslationData {
+    /// Movies and collections have a title, tv series, seasons and episodes
+    /// a name.
+    #[serde(alias = "name")]
+    pub title: String,
+    pub overview: String,
+    pub tagline: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct Translation {
+    pub iso_639_1: String,
+    pub iso_3166_1: String,
+    pub data: TranslationData,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+struct TranslationsPage {
+    translations: Vec<Translation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+struct Original {
+    original_language: String,
+    #[serde(alias = "original_name")]
+    original_title: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+struct OriginalWithTranslations {
+    #[serde(flatten)]
+    original: Original,
+    translations: TranslationsPage,
+}
+
+/// The title, overview and tagline picked from the translations. Empty values
+/// are skipped, so each field can come from a different language. Seasons,
+/// episodes and collections have no tagline.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Localized {
+    pub title: Option<String>,
+    pub overview: Option<String>,
+    pub tagline: Option<String>,
+}
+
+/// All translations of a title.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Translations {
+    /// ISO 639-1 code, empty if unknown.
+    pub original_language: String,
+    /// Empty for seasons and episodes.
+    pub original_title: String,
+    pub translations: Vec<Translation>,
+}
+
+impl Translations {
+    fn candidates<'a>(&'a self, step: &'a Fallback) -> impl Iterator<Item = &'a Translation> {
+        self.translations
+            .iter()
+            .filter(move |translation| match step {
+                Fallback::Locale(locale) => locale.matches(translation),
+                Fallback::Original => {
+                    !self.original_language.is_empty()
+                        && translation.iso_639_1 == self.original_language
+                }
+            })
+    }
+
+    /// The first non-empty value along the chain. The original step falls
+    /// back to `original`, if no translation in the original language has
+    /// the field.
+    fn pick(
+        &self,
+        chain: &FallbackChain,
+        field: impl Fn(&TranslationData) -> &str,
+        original: &str,
+    ) -> Option<String> {
+        chain.0.iter().find_map(|step| {
+            self.candidates(step)
+                .map(|translation| field(&translation.data))
+                .chain((step == &Fallback::Original).then_some(original))
+                .find(|value| !value.trim().is_empty())
+                .map(str::to_string)
+        })
+    }
+
+    pub fn localize(&self, chain: &FallbackChain) -> Localized {
+        Localized {
+            title: self.pick(chain, |data| &data.title, &self.original_title),
+            overview: self.pick(chain, |data| &data.overview, ""),
+            tagline: self.pick(chain, |data| &data.tagline, ""),
+        }
+    }
+}
+
+/// Everything, that can be localized with [`TmdbClient::localize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Localizable {
+    Movie(u64),
+    Tv(u64),
+    Season {
+        series_id: u64,
+        season: u32,
+    },
+    Episode {
+        series_id: u64,
+        season: u32,
+        episode: u32,
+    },
+    Collection(u64),
+}
+
+impl Localizable {
+    pub(crate) fn translations(self, client: &TmdbClient) -> Result<Translations, Error> {
+        let (original, translations) = match self {
+            Localizable::Movie(id) => {
+                let response: OriginalWithTranslations = client.get_details(
+                    "https://api.themoviedb.org/3/movie/{movie_id}",
+                    &format!("/3/movie/{id}"),
+                    None,
+                    &["translations"],
+                )?;
+                (response.original, response.translations)
+            }
+            Localizable::Tv(id) => {
+                let response: OriginalWithTranslations = client.get_details(
+                    "https://api.themoviedb.org/3/tv/{series_id}",
+                    &format!("/3/tv/{id}"),
+                    None,
+                    &["translations"],
+                )?;
+                (response.original, response.translations)
+            }
+            Localizable::Season { series_id, season } => {
+                // Seasons and episodes use the original language of the series.
+                let series: Original = client.get_json(
+                    "https://api.themoviedb.org/3/tv/{series_id}",
+                    &format!("/3/tv/{series_id}"),
+                    &[],
+                )?;
+                let translations = client.get_json(
+                    "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}/translations",
+                    &format!("/3/tv/{series_id}/season/{season}/translations"),
+                    &[],
+                )?;
+                (
+                    Original {
+                        original_title: String::new(),
+                        ..series
+                    },
+                    translations,
+                )
+            }
+            Localizable::Episode {
+                series_id,
+                season,
+                episode,
+            } => {
+                // Seasons and episodes use the original language of the series.
+                let series: Original = client.get_json(
+                    "https://api.themoviedb.org/3/tv/{series_id}",
+                    &format!("/3/tv/{series_id}"),
+                    &[],
+                )?;
+                let translations = client.get_json(
+                    "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}/episode/{episode_number}/translations",
+                    &format!("/3/tv/{series_id}/season/{season}/episode/{episode}/translations"),
+                    &[],
+                )?;
+                (
+                    Original {
+                        original_title: String::new(),
+                        ..series
+                    },
+                    translations,
+                )
+            }
+            Localizable::Collection(id) => {
+                let original = client.get_json(
+                    "https://api.themoviedb.org/3/collection/{collection_id}",
+                    &format!("/3/collection/{id}"),
+                    &[],
+                )?;
+                let translations = client.get_json(
+                    "https://api.themoviedb.org/3/collection/{collection_id}/translations",
+                    &format!("/3/collection/{id}/translations"),
+                    &[],
+                )?;
+                (original, translations)
+            }
+        };
+        Ok(Translations {
+            original_language: original.original_language,
+            original_title: original.original_title,
+            translations: translations.translations,
+        })
+    }
+}
+
+#[test]
+fn localize_with_fallback_chain() {
+    let response: OriginalWithTranslations = serde_json::from_str(
+        r#"{"original_language": "en", "original_title": "Fight Club", "translations": {"translations": [
+            {"iso_639_1": "de", "iso_3166_1": "DE", "data": {"title": "", "overview": "Ein Angestellter ...", "tagline": ""}},
+            {"iso_639_1": "de", "iso_3166_1": "AT", "data": {"title": "", "overview": "", "tagline": ""}},
+            {"iso_639_1": "en", "iso_3166_1": "US", "data": {"title": "", "overview": "A ticking-time-bomb ...", "tagline": "Mischief. Mayhem. Soap."}}
+        ]}}"#,
+    )
+    .unwrap();
+    let translations = Translations {
+        original_language: response.original.original_language,
+        original_title: response.original.original_title,
+        translations: response.translations.translations,
+    };
+    let chain: FallbackChain = "de-AT, de-DE, en-US, original".parse().unwrap();
+    let localized = translations.localize(&chain);
+    assert_eq!(localized.title.as_deref(), Some("Fight Club"));
+    assert_eq!(localized.overview.as_deref(), Some("Ein Angestellter ..."));
+    assert_eq!(
+        localized.tagline.as_deref(),
+        Some("Mischief. Mayhem. Soap.")
+    );
+    let localized = translations.localize(&"de-AT".parse().unwrap());
+    assert_eq!(localized, Localized::default());
+
+    // The original step is tried at its position, before later locales.
+    let chain: FallbackChain = "de-AT, original, de-DE".parse().unwrap();
+    let localized = translations.localize(&chain);
+    assert_eq!(localized.title.as_deref(), Some("Fight Club"));
+    assert_eq!(
+        localized.overview.as_deref(),
+        Some("A ticking-time-bomb ...")
+    );
+    assert_eq!(
+        localized.tagline.as_deref(),
+        Some("Mischief. Mayhem. Soap.")
+    );
+    assert!("deu-DE".parse::<FallbackChain>().is_err());
+}
@@ -0,0 +1,590 @@
+//! A local copy of movie, tv series, season and person details in SQLite.
+//! Lookups are answered from the local store and only fetched from tmdb, if
+//! they are missing. [`Mirror::sync`] refetches the stored entries, that
+//! changed on tmdb. Requires the `mirror` feature.
+
+use std::{
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::StatusCode;
+use rusqlite::{Connection, OptionalExtension, params};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    changes::{ChangeKind, ChangedId},
+    client::TmdbClient,
+    date::Date,
+    error::{Error, ErrorKind},
+    movie::MovieDetails,
+    person::PersonDetails,
+    tv::{TvSeasonDetails, TvSeriesDetails},
+};
+
+const SOURCE: &str = "tmdb-easy mirror";
+
+impl From<rusqlite::Error> for Error {
+    fn from(value: rusqlite::Error) -> Self {
+        Self {
+            source: SOURCE,
+            error: ErrorKind::StorageError(value),
+        }
+    }
+}
+
+/// An entry of the mirror.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MirrorKey {
+    Movie(u64),
+    Tv(u64),
+    Person(u64),
+    Season { series_id: u64, season: u32 },
+}
+
+impl MirrorKey {
+    /// The columns `kind`, `id` and `season` of the entry. Entries without a
+    /// season number use `-1`.
+    fn columns(self) -> (&'static str, i64, i64) {
+        match self {
+            MirrorKey::Movie(id) => ("movie", id as _, -1),
+            MirrorKey::Tv(id) => ("tv", id as _, -1),
+            MirrorKey::Person(id) => ("person", id as _, -1),
+            MirrorKey::Season { series_id, season } => ("season", series_id as _, season as _),
+        }
+    }
+
+    fn from_columns(kind: &str, id: i64, season: i64) -> Option<Self> {
+        let id = id as u64;
+        Some(match kind {
+            "movie" => MirrorKey::Movie(id),
+            "tv" => MirrorKey::Tv(id),
+            "person" => MirrorKey::Person(id),
+            "season" => MirrorKey::Season {
+                series_id: id,
+                season: season as _,
+            },
+            _ => return None,
+        })
+    }
+
+    /// The request used to fetch the entry, with the sections stored
+    /// alongside the details.
+    fn endpoint(self) -> (&'static str, String, &'static [&'static str]) {
+        match self {
+            MirrorKey::Movie(id) => (
+                "https://api.themoviedb.org/3/movie/{movie_id}",
+                format!("/3/movie/{id}"),
+                &["credits", "release_dates", "external_ids"],
+            ),
+            MirrorKey::Tv(id) => (
+                "https://api.themoviedb.org/3/tv/{series_id}",
+                format!("/3/tv/{id}"),
+                &["credits", "content_ratings", "external_ids"],
+            ),
+            MirrorKey::Person(id) => (
+                "https://api.themoviedb.org/3/person/{person_id}",
+                format!("/3/person/{id}"),
+                &["combined_credits", "external_ids"],
+            ),
+            MirrorKey::Season { series_id, season } => (
+                "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}",
+                format!("/3/tv/{series_id}/season/{season}"),
+                &["credits", "external_ids"],
+            ),
+        }
+    }
+}
+
+/// A stored entry and the language it was fetched in. The language is empty
+/// for entries fetched without one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StoredEntry {
+    pub key: MirrorKey,
+    pub language: String,
+}
+
+/// What [`Mirror::sync`] did.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SyncReport {
+    /// The number of changed ids reported by tmdb.
+    pub changed: usize,
+    /// The stored entries, that were fetched again, in every language.
+    pub refreshed: Vec<StoredEntry>,
+    /// The stored entries, tmdb no longer knows, e.g. deleted ids. They are
+    /// removed from the mirror.
+    pub removed: Vec<StoredEntry>,
+    /// The stored entries, tmdb refused to send for another reason. They are
+    /// kept as they were.
+    pub rejected: Vec<StoredEntry>,
+}
+
+pub struct Mirror<'a> {
+    client: &'a TmdbClient,
+    connection: Connection,
+}
+
+impl<'a> Mirror<'a> {
+    /// Opens or creates the mirror at `path`.
+    pub fn open(client: &'a TmdbClient, path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::with_connection(client, Connection::open(path)?)
+    }
+
+    /// A mirror, that is lost when dropped.
+    pub fn in_memory(client: &'a TmdbClient) -> Result<Self, Error> {
+        Self::with_connection(client, Connection::open_in_memory()?)
+    }
+
+    fn with_connection(client: &'a TmdbClient, connection: Connection) -> Result<Self, Error> {
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS entries (
+                kind TEXT NOT NULL,
+                id INTEGER NOT NULL,
+                season INTEGER NOT NULL,
+                language TEXT NOT NULL,
+                json TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY (kind, id, season, language)
+            );
+            CREATE TABLE IF NOT EXISTS sync_state (
+                kind TEXT PRIMARY KEY,
+                synced_until TEXT NOT NULL
+            );",
+        )?;
+        Ok(Self { client, connection })
+    }
+
+    /// Entries are stored per language of the client. Lookups, [`Mirror::seed`],
+    /// [`Mirror::refresh`] and [`Mirror::remove`] only use the current one,
+    /// [`Mirror::sync`] refreshes every stored language.
+    fn language(&self) -> &str {
+        self.client.language.as_deref().unwrap_or_default()
+    }
+
+    pub fn movie(&self, id: u64) -> Result<MovieDetails, Error> {
+        self.get(MirrorKey::Movie(id))
+    }
+
+    pub fn tv(&self, id: u64) -> Result<TvSeriesDetails, Error> {
+        self.get(MirrorKey::Tv(id))
+    }
+
+    pub fn season(&self, series_id: u64, season: u32) -> Result<TvSeasonDetails, Error> {
+        self.get(MirrorKey::Season { series_id, season })
+    }
+
+    pub fn person(&self, id: u64) -> Result<PersonDetails, Error> {
+        self.get(MirrorKey::Person(id))
+    }
+
+    fn get<T: DeserializeOwned>(&self, key: MirrorKey) -> Result<T, Error> {
+        let json = match self.stored(key)? {
+            Some(json) => json,
+            None => self.refresh(key)?,
+        };
+        serde_json::from_str(&json).map_err(|e| Error {
+            source: SOURCE,
+            error: ErrorKind::ParsingError(e.to_string(), json),
+        })
+    }
+
+    /// The stored response of `key`, without asking tmdb.
+    pub fn stored(&self, key: MirrorKey) -> Result<Option<String>, Error> {
+        let (kind, id, season) = key.columns();
+        Ok(self
+            .connection
+            .query_row(
+                "SELECT json FROM entries
+                 WHERE kind = ?1 AND id = ?2 AND season = ?3 AND language = ?4",
+                params![kind, id, season, self.language()],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Stores a response, e.g. one saved earlier, to fill the mirror without
+    /// network access.
+    pub fn seed(&self, key: MirrorKey, json: &str) -> Result<(), Error> {
+        self.store(key, self.language(), json)
+    }
+
+    fn store(&self, key: MirrorKey, language: &str, json: &str) -> Result<(), Error> {
+        let (kind, id, season) = key.columns();
+        let fetched_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or_default();
+        self.connection.execute(
+            "INSERT OR REPLACE INTO entries (kind, id, season, language, json, fetched_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![kind, id, season, language, json, fetched_at],
+        )?;
+        Ok(())
+    }
+
+    pub fn remove(&self, key: MirrorKey) -> Result<(), Error> {
+        self.remove_in(key, self.language())
+    }
+
+    fn remove_in(&self, key: MirrorKey, language: &str) -> Result<(), Error> {
+        let (kind, id, season) = key.columns();
+        self.connection.execute(
+            "DELETE FROM entries
+             WHERE kind = ?1 AND id = ?2 AND season = ?3 AND language = ?4",
+            params![kind, id, season, language],
+        )?;
+        Ok(())
+    }
+
+    /// Fetches the entry from tmdb and stores it.
+    pub fn refresh(&self, key: MirrorKey) -> Result<String, Error> {
+        let response = self.fetch(key, self.language());
+        self.store_response(key, self.language(), response)
+    }
+
+    fn fetch(&self, key: MirrorKey, language: &str) -> Result<serde_json::Value, Error> {
+        let (source, path, sections) = key.endpoint();
+        self.client.get_details(
+            source,
+            &path,
+            Some(language)
+                .filter(|language| !language.is_empty())
+                .map(Into::into),
+            sections,
+        )
+    }
+
+    /// Stores a fetched response. Rejected requests, e.g. for ids tmdb
+    /// deleted, keep the stored entry.
+    fn store_response(
+        &self,
+        key: MirrorKey,
+        language: &str,
+        response: Result<serde_json::Value, Error>,
+    ) -> Result<String, Error> {
+        let json = response?.to_string();
+        self.store(key, language, &json)?;
+        Ok(json)
+    }
+
+    /// The stored entries in every language, that are affected by the changed
+    /// ids. A changed tv series also affects its stored seasons.
+    pub fn stale(
+        &self,
+        kind: ChangeKind,
+        changed: &[ChangedId],
+    ) -> Result<Vec<StoredEntry>, Error> {
+        let kinds: &[&str] = match kind {
+            ChangeKind::Movie => &["movie"],
+            ChangeKind::Tv => &["tv", "season"],
+            ChangeKind::Person => &["person"],
+        };
+        let mut statement = self.connection.prepare(
+            "SELECT kind, id, season, language FROM entries
+             WHERE kind = ?1 AND id = ?2 ORDER BY season, language",
+        )?;
+        let mut stale = Vec::new();
+        for changed in changed {
+            for kind in kinds {
+                let rows = statement.query_map(params![kind, changed.id as i64], |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                    ))
+                })?;
+                for row in rows {
+                    let (kind, id, season, language) = row?;
+                    stale.extend(
+                        MirrorKey::from_columns(&kind, id, season)
+                            .map(|key| StoredEntry { key, language }),
+                    );
+                }
+            }
+        }
+        Ok(stale)
+    }
+
+    /// The end of the window of the last [`Mirror::sync`] of `kind`.
+    pub fn synced_until(&self, kind: ChangeKind) -> Result<Option<Date>, Error> {
+        let date: Option<String> = self
+            .connection
+            .query_row(
+                "SELECT synced_until FROM sync_state WHERE kind = ?1",
+                params![sync_kind(kind)],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(date.and_then(|date| date.parse().ok()))
+    }
+
+    /// Refetches the stored entries of `kind`, that changed between
+    /// `start_date` and `end_date`. tmdb allows windows of up to 14 days,
+    /// longer ones fail before anything is fetched. Entries tmdb rejects are
+    /// listed in the report, the window only counts as synced, once every
+    /// entry was handled.
+    pub fn sync(
+        &self,
+        kind: ChangeKind,
+        start_date: Date,
+        end_date: Date,
+    ) -> Result<SyncReport, Error> {
+        if !(0..=14).contains(&start_date.days_until(end_date)) {
+            return Err(Error {
+                source: SOURCE,
+                error: ErrorKind::InvalidWindow(start_date, end_date),
+            });
+        }
+        let changed = self
+            .client
+            .changed_ids(kind)
+            .with_start_date(start_date)
+            .with_end_date(end_date)
+            .load()?;
+        let mut report = SyncReport {
+            changed: changed.len(),
+            ..Default::default()
+        };
+        for entry in self.stale(kind, &changed)? {
+            let response = self.fetch(entry.key, &entry.language);
+            self.sync_entry(entry, response, &mut report)?;
+        }
+        self.connection.execute(
+            "INSERT OR REPLACE INTO sync_state (kind, synced_until) VALUES (?1, ?2)",
+            params![sync_kind(kind), end_date.to_string()],
+        )?;
+        Ok(report)
+    }
+
+    /// Stores the response of a stale entry. A `404` removes the entry,
+    /// other rejections keep it. Everything else, e.g. a network error,
+    /// stops the sync.
+    fn sync_entry(
+        &self,
+        entry: StoredEntry,
+        response: Result<serde_json::Value, Error>,
+        report: &mut SyncReport,
+    ) -> Result<(), Error> {
+        match self.store_response(entry.key, &entry.language, response) {
+            Ok(_) => report.refreshed.push(entry),
+            Err(Error {
+                error: ErrorKind::RequestRejected(StatusCode::NOT_FOUND, _),
+                ..
+            }) => {
+                self.remove_in(entry.key, &entry.language)?;
+                report.removed.push(entry);
+            }
+            Err(Error {
+                error: ErrorKind::RequestRejected(..),
+                ..
+            }) => report.rejected.push(entry),
+            Err(error) => return Err(error),
+        }
+        Ok(())
+    }
+}
+
+fn sync_kind(kind: ChangeKind) -> &'static str {
+    match kind {
+        ChangeKind::Movie => "movie",
+        ChangeKind::Tv => "tv",
+        ChangeKind::Person => "person",
+    }
+}
+
+#[test]
+fn mirror_answers_from_seeded_entries() {
+    // The api key is invalid, every lookup has to be answered locally.
+    let client = TmdbClient::new("offline");
+    let mirror = Mirror::in_memory(&client).unwrap();
+    mirror
+        .seed(
+            MirrorKey::Movie(550),
+            r#"{"id": 550, "title": "Fight Club", "credits": {"cast": [], "crew": []}}"#,
+        )
+        .unwrap();
+    mirror
+        .seed(
+            MirrorKey::Season {
+                series_id: 1399,
+                season: 1,
+            },
+            r#"{"id": 3624, "name": "Season 1", "season_number": 1}"#,
+        )
+        .unwrap();
+    mirror
+        .seed(
+            MirrorKey::Tv(1399),
+            r#"{"id": 1399, "name": "Game of Thrones"}"#,
+        )
+        .unwrap();
+
+    let movie = mirror.movie(550).unwrap();
+    assert_eq!(movie.details.title, "Fight Club");
+    assert!(movie.credits.is_some());
+    assert_eq!(mirror.season(1399, 1).unwrap().details.name, "Season 1");
+
+    let changed = [
+        ChangedId {
+            id: 1399,
+            adult: false,
+        },
+        ChangedId {
+            id: 42,
+            adult: false,
+        },
+    ];
+    // The same database, used by a client with another language.
+    let german = TmdbClient::new("offline")
+        .with_language("de-DE".parse::<crate::localization::Locale>().unwrap());
+    let german_mirror = Mirror {
+        client: &german,
+        connection: mirror.connection,
+    };
+    german_mirror
+        .seed(
+            MirrorKey::Tv(1399),
+            r#"{"id": 1399, "name": "Game of Thrones"}"#,
+        )
+        .unwrap();
+    let mirror = Mirror {
+        client: &client,
+        connection: german_mirror.connection,
+    };
+
+    let entry = |key, language: &str| StoredEntry {
+        key,
+        language: language.to_string(),
+    };
+    assert_eq!(
+        mirror.stale(ChangeKind::Tv, &changed).unwrap(),
+        [
+            entry(MirrorKey::Tv(1399), ""),
+            entry(MirrorKey::Tv(1399), "de-DE"),
+            entry(
+                MirrorKey::Season {
+                    series_id: 1399,
+                    season: 1
+                },
+                ""
+            ),
+        ]
+    );
+    assert!(
+        mirror
+            .stale(ChangeKind::Movie, &changed)
+            .unwrap()
+            .is_empty()
+    );
+
+    mirror.remove(MirrorKey::Movie(550)).unwrap();
+    assert_eq!(mirror.stored(MirrorKey::Movie(550)).unwrap(), None);
+    mirror.remove(MirrorKey::Tv(1399)).unwrap();
+    assert_eq!(
+        mirror.stale(ChangeKind::Tv, &changed).unwrap()[0],
+        entry(MirrorKey::Tv(1399), "de-DE")
+    );
+    assert_eq!(mirror.synced_until(ChangeKind::Movie).unwrap(), None);
+}
+
+#[test]
+fn rejected_refresh_keeps_the_stored_entry() {
+    let client = TmdbClient::new("offline");
+    let mirror = Mirror::in_memory(&client).unwrap();
+    let stored = r#"{"id": 550, "title": "Fight Club"}"#;
+    mirror.seed(MirrorKey::Movie(550), stored).unwrap();
+
+    let (source, _, _) = MirrorKey::Movie(550).endpoint();
+    let response = crate::client::read_json(
+        reqwest::StatusCode::NOT_FOUND,
+        r#"{"success": false, "status_code": 34, "status_message": "The resource you requested could not be found."}"#
+            .to_string(),
+        tmdb_easy_raw::ErrorContext {
+            source,
+            ..Default::default()
+        },
+    );
+    let error = mirror
+        .store_response(MirrorKey::Movie(550), "", response)
+        .unwrap_err();
+    assert!(matches!(
+        error.error,
+        ErrorKind::RequestRejected(reqwest::StatusCode::NOT_FOUND, _)
+    ));
+    assert_eq!(
+        mirror.stored(MirrorKey::Movie(550)).unwrap().as_deref(),
+        Some(stored)
+    );
+    assert_eq!(mirror.movie(550).unwrap().details.id, 550);
+}
+
+#[test]
+fn sync_handles_rejected_entries_one_by_one() {
+    let client = TmdbClient::new("offline");
+    let mirror = Mirror::in_memory(&client).unwrap();
+    let stored = r#"{"id": 550, "title": "Fight Club"}"#;
+    for id in [550, 551, 552] {
+        mirror.seed(MirrorKey::Movie(id), stored).unwrap();
+    }
+    let rejected = |status, status_code| {
+        crate::client::read_json(
+            status,
+            format!(
+                r#"{{"success": false, "status_code": {status_code}, "status_message": "No."}}"#
+            ),
+            tmdb_easy_raw::ErrorContext {
+                source: SOURCE,
+                ..Default::default()
+            },
+        )
+    };
+    let entry = |id| StoredEntry {
+        key: MirrorKey::Movie(id),
+        language: String::new(),
+    };
+
+    let mut report = SyncReport::default();
+    mirror
+        .sync_entry(entry(550), rejected(StatusCode::NOT_FOUND, 34), &mut report)
+        .unwrap();
+    mirror
+        .sync_entry(entry(551), rejected(StatusCode::FORBIDDEN, 3), &mut report)
+        .unwrap();
+    mirror
+        .sync_entry(
+            entry(552),
+            Ok(serde_json::json!({"id": 552, "title": "New"})),
+            &mut report,
+        )
+        .unwrap();
+    assert_eq!(report.removed, [entry(550)]);
+    assert_eq!(report.rejected, [entry(551)]);
+    assert_eq!(report.refreshed, [entry(552)]);
+    assert_eq!(mirror.stored(MirrorKey::Movie(550)).unwrap(), None);
+    assert_eq!(
+        mirror.stored(MirrorKey::Movie(551)).unwrap().as_deref(),
+        Some(stored)
+    );
+    assert_eq!(mirror.movie(552).unwrap().details.title, "New");
+
+    let error = mirror
+        .sync_entry(
+            entry(552),
+            rejected(StatusCode::UNAUTHORIZED, 7),
+            &mut report,
+        )
+        .unwrap_err();
+    assert!(matches!(error.error, ErrorKind::AuthenticationError));
+
+    // Too long windows fail before asking tmdb.
+    let error = mirror
+        .sync(
+            ChangeKind::Movie,
+            Date::new(2024, 1, 1),
+            Date::new(2024, 1, 16),
+        )
+        .unwrap_err();
+    assert!(matches!(error.error, ErrorKind::InvalidWindow(..)));
+    assert_eq!(mirror.synced_until(ChangeKind::Movie).unwrap(), None);
+}
@@ -1,22 +1,251 @@
-use crate::{MovieOrTvLike, client::TmdbClient, error::Error};
+use std::{borrow::Cow, fmt::Display};
 
-impl MovieOrTvLike for tmdb_easy_raw::types::SearchMovieResponse200Results {
-    fn id(&self) -> u64 {
-        self.id as _
+use tmdb_easy_raw::types::{
+    MovieCreditsResponse200, MovieDetailsResponse200, MovieExternalIdsResponse200,
+    MovieImagesResponse200, MovieKeywordsResponse200, MovieReleaseDatesResponse200,
+    MovieVideosResponse200,
+};
+
+use crate::{
+    MediaType, MovieOrTvLike,
+    certification::ReleaseDates,
+    client::TmdbClient,
+    collection::{CollectionBuilder, CollectionReference},
+    error::Error,
+    external_ids::ExternalIds,
+    impl_movie_or_tv_like,
+    keyword::Keywords,
+    localization::Locale,
+    review::ReviewsBuilder,
+    video::Videos,
+    watch_provider::WatchProviders,
+};
+
+/// The kinds of releases tmdb tracks per country.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReleaseType {
+    Premiere = 1,
+    TheatricalLimited = 2,
+    Theatrical = 3,
+    Digital = 4,
+    Physical = 5,
+    Tv = 6,
+}
+
+impl ReleaseType {
+    /// The release type for the number tmdb uses, e.g. `4` for digital.
+    pub fn from_number(number: i64) -> Option<Self> {
+        Some(match number {
+            1 => ReleaseType::Premiere,
+            2 => ReleaseType::TheatricalLimited,
+            3 => ReleaseType::Theatrical,
+            4 => ReleaseType::Digital,
+            5 => ReleaseType::Physical,
+            6 => ReleaseType::Tv,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for ReleaseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", *self as u8)
     }
+}
+
+impl_movie_or_tv_like!(
+    tmdb_easy_raw::types::SearchMovieResponse200Results,
+    MediaType::Movie
+);
+
+impl_movie_or_tv_like!(
+    tmdb_easy_raw::types::DiscoverMovieResponse200Results,
+    MediaType::Movie
+);
+
+impl_movie_or_tv_like!(
+    tmdb_easy_raw::types::TrendingMoviesResponse200Results,
+    MediaType::Movie
+);
+
+impl_movie_or_tv_like!(
+    tmdb_easy_raw::types::MoviePopularListResponse200Results,
+    MediaType::Movie
+);
+
+impl_movie_or_tv_like!(
+    tmdb_easy_raw::types::MovieTopRatedListResponse200Results,
+    MediaType::Movie
+);
+
+impl_movie_or_tv_like!(
+    tmdb_easy_raw::types::MovieUpcomingListResponse200Results,
+    MediaType::Movie
+);
 
-    fn poster_path(&self, _: &TmdbClient) -> Result<String, Error> {
-        Ok(self.poster_path.clone())
+impl_movie_or_tv_like!(
+    tmdb_easy_raw::types::MovieNowPlayingListResponse200Results,
+    MediaType::Movie
+);
+
+impl_movie_or_tv_like!(MovieDetailsResponse200, MediaType::Movie);
+
+/// The details of a movie, together with all the sections requested with
+/// the [`MovieBuilder`]. Sections, that were not requested, are `None`.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct MovieDetails {
+    #[serde(flatten)]
+    pub details: MovieDetailsResponse200,
+    /// Missing from the raw details, `None` if the movie is not part of a
+    /// collection.
+    #[serde(default)]
+    pub belongs_to_collection: Option<CollectionReference>,
+    pub credits: Option<MovieCreditsResponse200>,
+    pub videos: Option<MovieVideosResponse200>,
+    pub images: Option<MovieImagesResponse200>,
+    pub keywords: Option<MovieKeywordsResponse200>,
+    pub release_dates: Option<MovieReleaseDatesResponse200>,
+    pub external_ids: Option<MovieExternalIdsResponse200>,
+}
+
+impl MovieDetails {
+    /// Prepares loading the collection this movie belongs to, if any.
+    pub fn collection<'a>(&self, client: &'a TmdbClient) -> Option<CollectionBuilder<'a>> {
+        Some(self.belongs_to_collection.as_ref()?.details(client))
     }
 }
 
-impl MovieOrTvLike for tmdb_easy_raw::types::MovieDetailsResponse200 {
+impl MovieOrTvLike for MovieDetails {
     fn id(&self) -> u64 {
-        self.id as _
+        self.details.id as _
+    }
+
+    fn media_type(&self) -> MediaType {
+        MediaType::Movie
     }
 
     fn poster_path(&self, _client: &TmdbClient) -> Result<String, Error> {
-        Ok(self.poster_path.clone())
+        Ok(self.details.poster_path.clone())
+    }
+}
+
+/// Loads the details of a single movie. Additional sections are requested in
+/// the same request, using `append_to_response`.
+pub struct MovieBuilder<'a> {
+    client: &'a TmdbClient,
+    id: u64,
+    language: Option<Cow<'a, str>>,
+    append_to_response: Vec<&'static str>,
+}
+
+impl<'a> MovieBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, id: u64) -> Self {
+        Self {
+            client,
+            id,
+            language: client.language.clone(),
+            append_to_response: Vec::new(),
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_credits(self) -> Self {
+        self.append("credits")
+    }
+
+    pub fn with_videos(self) -> Self {
+        self.append("videos")
+    }
+
+    pub fn with_images(self) -> Self {
+        self.append("images")
+    }
+
+    pub fn with_keywords(self) -> Self {
+        self.append("keywords")
+    }
+
+    pub fn with_release_dates(self) -> Self {
+        self.append("release_dates")
+    }
+
+    pub fn with_external_ids(self) -> Self {
+        self.append("external_ids")
+    }
+
+    fn append(mut self, section: &'static str) -> Self {
+        if !self.append_to_response.contains(&section) {
+            self.append_to_response.push(section);
+        }
+        self
+    }
+
+    pub fn load(self) -> Result<MovieDetails, Error> {
+        self.client.get_details(
+            "https://api.themoviedb.org/3/movie/{movie_id}",
+            &format!("/3/movie/{}", self.id),
+            self.language,
+            &self.append_to_response,
+        )
+    }
+
+    /// The videos of the movie in the builder's language, with english and
+    /// language-less videos as fallback.
+    pub fn videos(self) -> Result<Videos, Error> {
+        Videos::fetch(
+            self.client,
+            "https://api.themoviedb.org/3/movie/{movie_id}/videos",
+            &format!("/3/movie/{}/videos", self.id),
+            self.language.as_deref(),
+        )
+    }
+
+    /// The releases and certifications of the movie, in every region.
+    pub fn release_dates(self) -> Result<ReleaseDates, Error> {
+        let response: MovieReleaseDatesResponse200 = self.client.get_json(
+            "https://api.themoviedb.org/3/movie/{movie_id}/release_dates",
+            &format!("/3/movie/{}/release_dates", self.id),
+            &[],
+        )?;
+        Ok(response.into())
+    }
+
+    pub fn keywords(self) -> Result<Keywords, Error> {
+        let response: MovieKeywordsResponse200 = self.client.get_json(
+            "https://api.themoviedb.org/3/movie/{movie_id}/keywords",
+            &format!("/3/movie/{}/keywords", self.id),
+            &[],
+        )?;
+        Ok(response.into())
+    }
+
+    /// Lists the reviews of the movie, page by page.
+    pub fn reviews(self) -> ReviewsBuilder<'a> {
+        let mut builder = ReviewsBuilder::new(self.client, MediaType::Movie, self.id);
+        builder.language = self.language;
+        builder
+    }
+
+    /// The ids of the movie on other sites, like IMDb.
+    pub fn external_ids(self) -> Result<ExternalIds, Error> {
+        ExternalIds::fetch(
+            self.client,
+            "https://api.themoviedb.org/3/movie/{movie_id}/external_ids",
+            &format!("/3/movie/{}/external_ids", self.id),
+        )
+    }
+
+    /// Where the movie can be streamed, rented or bought, in every region.
+    pub fn watch_providers(self) -> Result<WatchProviders, Error> {
+        self.client.get_json(
+            "https://api.themoviedb.org/3/movie/{movie_id}/watch/providers",
+            &format!("/3/movie/{}/watch/providers", self.id),
+            &[],
+        )
     }
 }
 
@@ -31,3 +260,22 @@ fn try_poster_download() -> Result<(), Error> {
         .expect("works");
     Ok(())
 }
+
+#[test]
+fn movie_details_with_appended_sections() {
+    let movie: MovieDetails = serde_json::from_str(
+        r#"{
+            "id": 550,
+            "title": "Fight Club",
+            "poster_path": "/poster.jpg",
+            "belongs_to_collection": {"id": 10, "name": "Collection", "poster_path": null},
+            "credits": {"cast": [{"id": 819, "name": "Edward Norton"}], "crew": []}
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(movie.details.title, "Fight Club");
+    assert_eq!(movie.id(), 550);
+    assert_eq!(movie.credits.unwrap().cast[0].name, "Edward Norton");
+    assert!(movie.videos.is_none());
+    assert_eq!(movie.belongs_to_collection.unwrap().id, 10);
+}
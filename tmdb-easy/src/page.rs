@@ -0,0 +1,179 @@
+//! Paging for the list endpoints. Builders, that load one page of results
+//! at a time, implement [`PagedBuilder`] and return a [`PagedResponse`].
+
+use std::ops::Index;
+
+use crate::error::Error;
+
+/// A single page of results, as returned by tmdb. Implemented by the raw
+/// response types and by [`Page`].
+pub trait ResultPage {
+    type Item;
+
+    fn page(&self) -> i64;
+    fn total_pages(&self) -> i64;
+    fn total_results(&self) -> i64;
+    fn results(&self) -> &[Self::Item];
+}
+
+/// Implements [`ResultPage`] for raw response types with a `results` list.
+macro_rules! result_page {
+    ($response:ty, $item:ty) => {
+        impl $crate::page::ResultPage for $response {
+            type Item = $item;
+
+            fn page(&self) -> i64 {
+                self.page
+            }
+
+            fn total_pages(&self) -> i64 {
+                self.total_pages
+            }
+
+            fn total_results(&self) -> i64 {
+                self.total_results
+            }
+
+            fn results(&self) -> &[Self::Item] {
+                &self.results
+            }
+        }
+    };
+}
+pub(crate) use result_page;
+
+/// A page for endpoints without a generated response type, or with results
+/// converted into the types of this crate. The defaults are per field, so
+/// the items need no `Default`.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(bound = "T: serde::de::DeserializeOwned")]
+pub struct Page<T> {
+    #[serde(default)]
+    pub page: i64,
+    #[serde(default)]
+    pub total_pages: i64,
+    #[serde(default)]
+    pub total_results: i64,
+    #[serde(default = "Vec::new")]
+    pub results: Vec<T>,
+}
+
+impl<T> Page<T> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            page: self.page,
+            total_pages: self.total_pages,
+            total_results: self.total_results,
+            results: self.results.into_iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> ResultPage for Page<T> {
+    type Item = T;
+
+    fn page(&self) -> i64 {
+        self.page
+    }
+
+    fn total_pages(&self) -> i64 {
+        self.total_pages
+    }
+
+    fn total_results(&self) -> i64 {
+        self.total_results
+    }
+
+    fn results(&self) -> &[Self::Item] {
+        &self.results
+    }
+}
+
+/// A builder for one of the list endpoints.
+pub trait PagedBuilder: Sized {
+    type Page: ResultPage;
+
+    /// Loads the page set with `with_page`, the first one by default.
+    fn fetch_page(&self) -> Result<Self::Page, Error>;
+    fn set_page(&mut self, page: i64);
+}
+
+/// A page of results, together with the builder to load the next one.
+pub struct PagedResponse<B: PagedBuilder> {
+    pub(crate) builder: B,
+    pub(crate) page: B::Page,
+}
+
+impl<B: PagedBuilder> Index<usize> for PagedResponse<B> {
+    type Output = <B::Page as ResultPage>::Item;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.page.results()[index]
+    }
+}
+
+impl<B: PagedBuilder> PagedResponse<B> {
+    pub(crate) fn load(builder: B) -> Result<Self, Error> {
+        let page = builder.fetch_page()?;
+        Ok(Self { builder, page })
+    }
+
+    pub fn current_page(&self) -> &[<B::Page as ResultPage>::Item] {
+        self.page.results()
+    }
+
+    /// The number of the current page, starting at 1.
+    pub fn page(&self) -> i64 {
+        self.page.page()
+    }
+
+    pub fn total_pages(&self) -> i64 {
+        self.page.total_pages()
+    }
+
+    pub fn total_results(&self) -> i64 {
+        self.page.total_results()
+    }
+
+    pub fn next_page(mut self) -> Option<Result<Self, Error>> {
+        if self.page.page() >= self.page.total_pages() {
+            None
+        } else {
+            self.builder.set_page(self.page.page() + 1);
+            Some(Self::load(self.builder))
+        }
+    }
+}
+
+#[test]
+fn pages_until_the_last_one() {
+    struct Numbers {
+        page: i64,
+    }
+
+    impl PagedBuilder for Numbers {
+        type Page = Page<i64>;
+
+        fn fetch_page(&self) -> Result<Self::Page, Error> {
+            let page: Page<i64> = serde_json::from_str(&format!(
+                r#"{{"page": {}, "total_pages": 3, "results": [{}]}}"#,
+                self.page, self.page
+            ))
+            .unwrap();
+            Ok(page.map(|n| n * 10))
+        }
+
+        fn set_page(&mut self, page: i64) {
+            self.page = page;
+        }
+    }
+
+    let mut response = PagedResponse::load(Numbers { page: 1 }).unwrap();
+    assert_eq!(response.total_results(), 0);
+    let mut seen = vec![response[0]];
+    while let Some(next) = response.next_page() {
+        response = next.unwrap();
+        seen.extend_from_slice(response.current_page());
+    }
+    assert_eq!(seen, [10, 20, 30]);
+}
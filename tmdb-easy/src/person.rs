@@ -0,0 +1,386 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use image::DynamicImage;
+use tmdb_easy_raw::types::{
+    MovieCreditsResponse200Cast, MovieCreditsResponse200Crew, PersonCombinedCreditsResponse200,
+    PersonDetailsResponse200, PersonExternalIdsResponse200, PersonImagesResponse200,
+    PersonMovieCreditsResponse200, PersonTvCreditsResponse200, SearchPersonResponse200Results,
+    TrendingPeopleResponse200Results, TvEpisodeCreditsResponse200Cast,
+    TvEpisodeCreditsResponse200Crew, TvEpisodeCreditsResponse200GuestStars,
+    TvSeasonAggregateCreditsResponse200Cast, TvSeasonCreditsResponse200Cast,
+    TvSeriesAggregateCreditsResponse200Cast, TvSeriesAggregateCreditsResponse200Crew,
+    TvSeriesCreditsResponse200Cast, TvSeriesCreditsResponse200Crew,
+};
+
+use crate::{
+    MediaType,
+    client::{ImageKind, TmdbClient},
+    configuration::Department,
+    date::Date,
+    error::{Error, ErrorKind},
+    external_ids::ExternalIds,
+    localization::Locale,
+};
+
+pub trait PersonLike {
+    fn id(&self) -> u64;
+    fn name(&self) -> &str;
+    fn profile_path(&self, client: &TmdbClient) -> Result<String, Error>;
+}
+
+pub trait PersonLikeExt: PersonLike {
+    fn profile(&self, client: &mut TmdbClient) -> Result<DynamicImage, Error> {
+        let profile_path = self.profile_path(client)?;
+        client.resolve_image_path(profile_path, ImageKind::Profile)
+    }
+}
+
+impl<T: PersonLike + ?Sized> PersonLikeExt for T {}
+
+macro_rules! person_like {
+    ($($person:ty),* $(,)?) => {
+        $(
+            impl PersonLike for $person {
+                fn id(&self) -> u64 {
+                    self.id as _
+                }
+
+                fn name(&self) -> &str {
+                    &self.name
+                }
+
+                fn profile_path(&self, _client: &TmdbClient) -> Result<String, Error> {
+                    Ok(self.profile_path.clone())
+                }
+            }
+        )*
+    };
+}
+
+person_like!(
+    SearchPersonResponse200Results,
+    TrendingPeopleResponse200Results,
+    PersonDetailsResponse200,
+    MovieCreditsResponse200Cast,
+    MovieCreditsResponse200Crew,
+    TvSeriesCreditsResponse200Cast,
+    TvSeriesCreditsResponse200Crew,
+    TvSeriesAggregateCreditsResponse200Cast,
+    TvSeriesAggregateCreditsResponse200Crew,
+    TvSeasonCreditsResponse200Cast,
+    TvSeasonAggregateCreditsResponse200Cast,
+    TvEpisodeCreditsResponse200Cast,
+    TvEpisodeCreditsResponse200Crew,
+    TvEpisodeCreditsResponse200GuestStars,
+);
+
+/// The details of a person, together with all the sections requested with
+/// the [`PersonBuilder`]. Sections, that were not requested, are `None`.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct PersonDetails {
+    #[serde(flatten)]
+    pub details: PersonDetailsResponse200,
+    pub combined_credits: Option<PersonCombinedCreditsResponse200>,
+    pub movie_credits: Option<PersonMovieCreditsResponse200>,
+    pub tv_credits: Option<PersonTvCreditsResponse200>,
+    pub images: Option<PersonImagesResponse200>,
+    pub external_ids: Option<PersonExternalIdsResponse200>,
+}
+
+impl PersonDetails {
+    /// The filmography of this person, if both the movie and the tv credits
+    /// were requested.
+    pub fn filmography(&self) -> Option<Filmography> {
+        Some(Filmography::from_credits(
+            self.movie_credits.as_ref()?,
+            self.tv_credits.as_ref()?,
+        ))
+    }
+}
+
+impl PersonLike for PersonDetails {
+    fn id(&self) -> u64 {
+        self.details.id as _
+    }
+
+    fn name(&self) -> &str {
+        &self.details.name
+    }
+
+    fn profile_path(&self, _client: &TmdbClient) -> Result<String, Error> {
+        Ok(self.details.profile_path.clone())
+    }
+}
+
+/// Loads the details of a person. Additional sections are requested in the
+/// same request, using `append_to_response`.
+pub struct PersonBuilder<'a> {
+    client: &'a TmdbClient,
+    id: u64,
+    language: Option<Cow<'a, str>>,
+    append_to_response: Vec<&'static str>,
+}
+
+impl<'a> PersonBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, id: u64) -> Self {
+        Self {
+            client,
+            id,
+            language: client.language.clone(),
+            append_to_response: Vec::new(),
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_combined_credits(self) -> Self {
+        self.append("combined_credits")
+    }
+
+    pub fn with_movie_credits(self) -> Self {
+        self.append("movie_credits")
+    }
+
+    pub fn with_tv_credits(self) -> Self {
+        self.append("tv_credits")
+    }
+
+    pub fn with_images(self) -> Self {
+        self.append("images")
+    }
+
+    pub fn with_external_ids(self) -> Self {
+        self.append("external_ids")
+    }
+
+    fn append(mut self, section: &'static str) -> Self {
+        if !self.append_to_response.contains(&section) {
+            self.append_to_response.push(section);
+        }
+        self
+    }
+
+    pub fn load(self) -> Result<PersonDetails, Error> {
+        self.client.get_details(
+            "https://api.themoviedb.org/3/person/{person_id}",
+            &format!("/3/person/{}", self.id),
+            self.language,
+            &self.append_to_response,
+        )
+    }
+
+    /// Loads the movie and tv credits and combines them into a filmography.
+    pub fn filmography(self) -> Result<Filmography, Error> {
+        let details = self.with_movie_credits().with_tv_credits().load()?;
+        details.filmography().ok_or(Error {
+            source: "https://api.themoviedb.org/3/person/{person_id}",
+            error: ErrorKind::MissingSection("movie_credits and tv_credits"),
+        })
+    }
+
+    /// The ids of the person on other sites, like IMDb.
+    pub fn external_ids(self) -> Result<ExternalIds, Error> {
+        ExternalIds::fetch(
+            self.client,
+            "https://api.themoviedb.org/3/person/{person_id}/external_ids",
+            &format!("/3/person/{}/external_ids", self.id),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Role {
+    Cast { character: String },
+    Crew { department: Department, job: String },
+}
+
+impl Role {
+    /// The department of this role, cast members are part of `Acting`.
+    pub fn department(&self) -> Department {
+        match self {
+            Role::Cast { .. } => Department::Acting,
+            Role::Crew { department, .. } => department.clone(),
+        }
+    }
+
+    /// The job of this role, cast members work as `Actor`.
+    pub fn job(&self) -> &str {
+        match self {
+            Role::Cast { .. } => "Actor",
+            Role::Crew { job, .. } => job,
+        }
+    }
+}
+
+/// A single movie or tv series a person worked on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilmographyEntry {
+    pub media_type: MediaType,
+    pub id: u64,
+    pub title: String,
+    /// The release date of a movie or the first air date of a tv series.
+    pub date: Option<Date>,
+    pub poster_path: String,
+    pub role: Role,
+    /// Only known for tv series.
+    pub episode_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Filmography {
+    pub entries: Vec<FilmographyEntry>,
+}
+
+impl Filmography {
+    pub fn from_credits(
+        movie_credits: &PersonMovieCreditsResponse200,
+        tv_credits: &PersonTvCreditsResponse200,
+    ) -> Self {
+        let movie_cast = movie_credits.cast.iter().map(|c| FilmographyEntry {
+            media_type: MediaType::Movie,
+            id: c.id as _,
+            title: c.title.clone(),
+            date: c.release_date.parse().ok(),
+            poster_path: c.poster_path.clone(),
+            role: Role::Cast {
+                character: c.character.clone(),
+            },
+            episode_count: None,
+        });
+        let movie_crew = movie_credits.crew.iter().map(|c| FilmographyEntry {
+            media_type: MediaType::Movie,
+            id: c.id as _,
+            title: c.title.clone(),
+            date: c.release_date.parse().ok(),
+            poster_path: c.poster_path.clone(),
+            role: Role::Crew {
+                department: c.department.clone().into(),
+                job: c.job.clone(),
+            },
+            episode_count: None,
+        });
+        let tv_cast = tv_credits.cast.iter().map(|c| FilmographyEntry {
+            media_type: MediaType::Tv,
+            id: c.id as _,
+            title: c.name.clone(),
+            date: c.first_air_date.parse().ok(),
+            poster_path: c.poster_path.clone(),
+            role: Role::Cast {
+                character: c.character.clone(),
+            },
+            episode_count: Some(c.episode_count as _),
+        });
+        let tv_crew = tv_credits.crew.iter().map(|c| FilmographyEntry {
+            media_type: MediaType::Tv,
+            id: c.id as _,
+            title: c.name.clone(),
+            date: c.first_air_date.parse().ok(),
+            poster_path: c.poster_path.clone(),
+            role: Role::Crew {
+                department: c.department.clone().into(),
+                job: c.job.clone(),
+            },
+            episode_count: Some(c.episode_count as _),
+        });
+        Self {
+            entries: movie_cast
+                .chain(movie_crew)
+                .chain(tv_cast)
+                .chain(tv_crew)
+                .collect(),
+        }
+    }
+
+    /// Merges entries for the same title and the same job. Tv series often
+    /// list a person once per character or season, those are combined into a
+    /// single entry, with the episode counts summed up and the characters
+    /// joined by ` / `.
+    pub fn deduplicated(self) -> Self {
+        let mut entries: Vec<FilmographyEntry> = Vec::with_capacity(self.entries.len());
+        for entry in self.entries {
+            let existing = entries.iter_mut().find(|e| {
+                e.media_type == entry.media_type
+                    && e.id == entry.id
+                    && e.role.department() == entry.role.department()
+                    && e.role.job() == entry.role.job()
+            });
+            let Some(existing) = existing else {
+                entries.push(entry);
+                continue;
+            };
+            if let (Role::Cast { character }, Role::Cast { character: other }) =
+                (&mut existing.role, entry.role)
+                && !other.is_empty()
+                && !character.split(" / ").any(|c| c == other)
+            {
+                if !character.is_empty() {
+                    character.push_str(" / ");
+                }
+                character.push_str(&other);
+            }
+            existing.episode_count = match (existing.episode_count, entry.episode_count) {
+                (Some(a), Some(b)) => Some(a + b),
+                (a, b) => a.or(b),
+            };
+        }
+        Self { entries }
+    }
+
+    /// Sorts by release or first air date, oldest first. Entries without a
+    /// date are put last.
+    pub fn sort_by_date(&mut self) {
+        self.entries
+            .sort_by_key(|e| (e.date.is_none(), e.date, e.title.clone()));
+    }
+
+    pub fn by_department(&self) -> BTreeMap<Department, Vec<&FilmographyEntry>> {
+        let mut result: BTreeMap<Department, Vec<&FilmographyEntry>> = BTreeMap::new();
+        for entry in &self.entries {
+            result
+                .entry(entry.role.department())
+                .or_default()
+                .push(entry);
+        }
+        result
+    }
+
+    pub fn by_job(&self) -> BTreeMap<&str, Vec<&FilmographyEntry>> {
+        let mut result: BTreeMap<&str, Vec<&FilmographyEntry>> = BTreeMap::new();
+        for entry in &self.entries {
+            result.entry(entry.role.job()).or_default().push(entry);
+        }
+        result
+    }
+}
+
+#[test]
+fn filmography_deduplicates_tv_roles() {
+    let tv_credits: PersonTvCreditsResponse200 = serde_json::from_str(
+        r#"{"cast": [
+            {"id": 1, "name": "Show", "character": "A", "episode_count": 3, "first_air_date": "2010-01-01"},
+            {"id": 1, "name": "Show", "character": "B", "episode_count": 2, "first_air_date": "2010-01-01"}
+        ]}"#,
+    )
+    .unwrap();
+    let movie_credits: PersonMovieCreditsResponse200 = serde_json::from_str(
+        r#"{"cast": [{"id": 2, "title": "Movie", "character": "C", "release_date": "2005-05-05"}],
+            "crew": [{"id": 2, "title": "Movie", "department": "Directing", "job": "Director", "release_date": "2005-05-05"}]}"#,
+    )
+    .unwrap();
+    let mut filmography = Filmography::from_credits(&movie_credits, &tv_credits).deduplicated();
+    filmography.sort_by_date();
+    assert_eq!(filmography.entries.len(), 3);
+    assert_eq!(filmography.entries[0].title, "Movie");
+    let show = &filmography.entries[2];
+    assert_eq!(show.episode_count, Some(5));
+    assert_eq!(
+        show.role,
+        Role::Cast {
+            character: "A / B".into()
+        }
+    );
+    assert_eq!(filmography.by_department()[&Department::Directing].len(), 1);
+}
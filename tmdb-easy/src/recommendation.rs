@@ -0,0 +1,171 @@
+use std::borrow::Cow;
+
+use tmdb_easy_raw::types::{MovieSimilarResponse200Results, TvSeriesSimilarResponse200Results};
+
+use crate::{
+    MediaType, MovieOrTvLike,
+    client::TmdbClient,
+    error::Error,
+    impl_movie_or_tv_like,
+    localization::Locale,
+    page::{Page, PagedBuilder, PagedResponse},
+};
+
+/// Recommendations are based on what tmdb users liked together, similar
+/// titles on genres and keywords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecommendationKind {
+    Recommendations,
+    Similar,
+}
+
+/// A recommended movie or tv series. Movies only lead to movies and tv
+/// series only to tv series.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecommendedItem {
+    Movie(MovieSimilarResponse200Results),
+    Tv(TvSeriesSimilarResponse200Results),
+}
+
+impl MovieOrTvLike for RecommendedItem {
+    fn id(&self) -> u64 {
+        match self {
+            RecommendedItem::Movie(it) => it.id(),
+            RecommendedItem::Tv(it) => it.id(),
+        }
+    }
+
+    fn media_type(&self) -> MediaType {
+        match self {
+            RecommendedItem::Movie(it) => it.media_type(),
+            RecommendedItem::Tv(it) => it.media_type(),
+        }
+    }
+
+    fn poster_path(&self, client: &TmdbClient) -> Result<String, Error> {
+        match self {
+            RecommendedItem::Movie(it) => it.poster_path(client),
+            RecommendedItem::Tv(it) => it.poster_path(client),
+        }
+    }
+}
+
+impl_movie_or_tv_like!(MovieSimilarResponse200Results, MediaType::Movie);
+
+impl_movie_or_tv_like!(TvSeriesSimilarResponse200Results, MediaType::Tv);
+
+pub type RecommendationsResponse<'a> = PagedResponse<RecommendationsBuilder<'a>>;
+
+/// Lists the recommendations or similar titles of a movie or tv series.
+pub struct RecommendationsBuilder<'a> {
+    client: &'a TmdbClient,
+    media_type: MediaType,
+    id: u64,
+    kind: RecommendationKind,
+    language: Option<Cow<'a, str>>,
+    page: Option<i64>,
+}
+
+impl<'a> RecommendationsBuilder<'a> {
+    pub fn new(
+        client: &'a TmdbClient,
+        media_type: MediaType,
+        id: u64,
+        kind: RecommendationKind,
+    ) -> Self {
+        Self {
+            client,
+            media_type,
+            id,
+            kind,
+            language: client.language.clone(),
+            page: None,
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn search(self) -> Result<RecommendationsResponse<'a>, Error> {
+        PagedResponse::load(self)
+    }
+}
+
+impl PagedBuilder for RecommendationsBuilder<'_> {
+    type Page = Page<RecommendedItem>;
+
+    fn fetch_page(&self) -> Result<Self::Page, Error> {
+        let mut query = Vec::new();
+        if let Some(language) = &self.language {
+            query.push(("language", language.to_string()));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        let (source, path) = match (self.media_type, self.kind) {
+            (MediaType::Movie, RecommendationKind::Recommendations) => (
+                "https://api.themoviedb.org/3/movie/{movie_id}/recommendations",
+                format!("/3/movie/{}/recommendations", self.id),
+            ),
+            (MediaType::Movie, RecommendationKind::Similar) => (
+                "https://api.themoviedb.org/3/movie/{movie_id}/similar",
+                format!("/3/movie/{}/similar", self.id),
+            ),
+            (MediaType::Tv, RecommendationKind::Recommendations) => (
+                "https://api.themoviedb.org/3/tv/{series_id}/recommendations",
+                format!("/3/tv/{}/recommendations", self.id),
+            ),
+            (MediaType::Tv, RecommendationKind::Similar) => (
+                "https://api.themoviedb.org/3/tv/{series_id}/similar",
+                format!("/3/tv/{}/similar", self.id),
+            ),
+        };
+        Ok(match self.media_type {
+            MediaType::Movie => {
+                let response: Page<MovieSimilarResponse200Results> =
+                    self.client.get_json(source, &path, &query)?;
+                response.map(RecommendedItem::Movie)
+            }
+            MediaType::Tv => {
+                let response: Page<TvSeriesSimilarResponse200Results> =
+                    self.client.get_json(source, &path, &query)?;
+                response.map(RecommendedItem::Tv)
+            }
+        })
+    }
+
+    fn set_page(&mut self, page: i64) {
+        self.page = Some(page);
+    }
+}
+
+#[test]
+fn recommendations_keep_media_type() {
+    let page: Page<TvSeriesSimilarResponse200Results> = serde_json::from_str(
+        r#"{"page": 1, "total_pages": 3, "results": [
+            {"id": 1399, "name": "Game of Thrones", "poster_path": "/got.jpg", "media_type": "tv",
+             "vote_average": 8.456}
+        ]}"#,
+    )
+    .unwrap();
+    let item = RecommendedItem::Tv(page.results.into_iter().next().unwrap());
+    let RecommendedItem::Tv(series) = &item else {
+        unreachable!()
+    };
+    assert_eq!(series.vote_average, 8.456);
+    let client = TmdbClient::new("offline");
+    assert_eq!(item.media_type(), MediaType::Tv);
+    assert_eq!(item.poster_path(&client).unwrap(), "/got.jpg");
+    let builder = crate::MovieOrTvLikeExt::similar(&item, &client);
+    assert_eq!(
+        (builder.media_type, builder.id, builder.kind),
+        (MediaType::Tv, 1399, RecommendationKind::Similar)
+    );
+}
@@ -0,0 +1,74 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::configuration::ReferenceData;
+
+/// A country as an ISO 3166-1 code, like `DE` or `US`. tmdb uses these for
+/// release dates, certifications and watch providers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Deserialize)]
+#[serde(try_from = "String")]
+pub struct Region([u8; 2]);
+
+impl Region {
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).expect("only ascii letters are accepted")
+    }
+
+    /// Whether tmdb knows this country, according to the vendored snapshot
+    /// of the configuration.
+    pub fn is_known(&self) -> bool {
+        self.is_known_in(ReferenceData::snapshot())
+    }
+
+    pub fn is_known_in(&self, data: &ReferenceData) -> bool {
+        data.country(*self).is_some()
+    }
+
+    /// E.g. `Germany` for `DE`, `None` for unknown countries.
+    pub fn english_name(&self) -> Option<&'static str> {
+        ReferenceData::snapshot()
+            .country(*self)
+            .map(|country| country.english_name.as_str())
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid region, expected a two letter country code: {0:?}")]
+pub struct InvalidRegion(pub String);
+
+impl FromStr for Region {
+    type Err = InvalidRegion;
+
+    /// Accepts upper and lower case, e.g. `de` and `DE`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            [a, b] if a.is_ascii_alphabetic() && b.is_ascii_alphabetic() => {
+                Ok(Self([a.to_ascii_uppercase(), b.to_ascii_uppercase()]))
+            }
+            _ => Err(InvalidRegion(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for Region {
+    type Error = InvalidRegion;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[test]
+fn region_codes() {
+    let region: Region = "de".parse().unwrap();
+    assert_eq!(region.to_string(), "DE");
+    assert!("DEU".parse::<Region>().is_err());
+    assert!("1A".parse::<Region>().is_err());
+    assert_eq!(region.english_name(), Some("Germany"));
+    assert!(!"QQ".parse::<Region>().unwrap().is_known());
+}
@@ -0,0 +1,225 @@
+use std::borrow::Cow;
+
+use crate::{
+    MediaType,
+    client::{ImageKind, TmdbClient, path_with_segment},
+    date::DateTime,
+    error::Error,
+    localization::Locale,
+    page::{Page, PagedBuilder, PagedResponse},
+};
+
+/// The tmdb user, who wrote a review.
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct ReviewAuthor {
+    pub name: String,
+    pub username: String,
+    pub avatar_path: Option<String>,
+    /// The rating the author gave, from `0` to `10`.
+    pub rating: Option<f64>,
+}
+
+impl ReviewAuthor {
+    /// The full url of the avatar. Avatars hosted elsewhere, e.g. on
+    /// gravatar, come as a full url with a leading slash. Returns `None`, if
+    /// the author has no avatar.
+    pub fn avatar_url(&self, client: &mut TmdbClient) -> Result<Option<String>, Error> {
+        let Some(path) = self.avatar_path.as_deref().filter(|path| !path.is_empty()) else {
+            return Ok(None);
+        };
+        let external = path.trim_start_matches('/');
+        if external.starts_with("http://") || external.starts_with("https://") {
+            return Ok(Some(external.to_string()));
+        }
+        client.image_url(path, ImageKind::Profile).map(Some)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+struct RawReview {
+    id: String,
+    author: String,
+    author_details: ReviewAuthor,
+    content: String,
+    created_at: String,
+    updated_at: String,
+    url: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Review {
+    pub id: String,
+    pub author: ReviewAuthor,
+    pub content: String,
+    pub created_at: Option<DateTime>,
+    pub updated_at: Option<DateTime>,
+    pub url: String,
+}
+
+impl From<RawReview> for Review {
+    fn from(value: RawReview) -> Self {
+        let mut author = value.author_details;
+        if author.name.is_empty() {
+            author.name = value.author;
+        }
+        Self {
+            id: value.id,
+            author,
+            content: value.content,
+            created_at: value.created_at.parse().ok(),
+            updated_at: value.updated_at.parse().ok(),
+            url: value.url,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+struct RawReviewDetails {
+    #[serde(flatten)]
+    review: RawReview,
+    iso_639_1: String,
+    media_id: i64,
+    media_title: String,
+    media_type: String,
+}
+
+/// A single review, together with the title it is about.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewDetails {
+    pub review: Review,
+    pub language: String,
+    pub media_id: u64,
+    pub media_title: String,
+    /// `None`, if tmdb reports an unknown media type.
+    pub media_type: Option<MediaType>,
+}
+
+impl From<RawReviewDetails> for ReviewDetails {
+    fn from(value: RawReviewDetails) -> Self {
+        Self {
+            review: value.review.into(),
+            language: value.iso_639_1,
+            media_id: value.media_id as _,
+            media_title: value.media_title,
+            media_type: match value.media_type.as_str() {
+                "movie" => Some(MediaType::Movie),
+                "tv" => Some(MediaType::Tv),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl ReviewDetails {
+    pub(crate) fn fetch(client: &TmdbClient, id: &str) -> Result<Self, Error> {
+        let response: RawReviewDetails = client.get_json(
+            "https://api.themoviedb.org/3/review/{review_id}",
+            &path_with_segment("/3/review", id),
+            &[],
+        )?;
+        Ok(response.into())
+    }
+}
+
+pub type ReviewsResponse<'a> = PagedResponse<ReviewsBuilder<'a>>;
+
+/// Lists the reviews of a movie or tv series.
+pub struct ReviewsBuilder<'a> {
+    client: &'a TmdbClient,
+    media_type: MediaType,
+    id: u64,
+    pub(crate) language: Option<Cow<'a, str>>,
+    page: Option<i64>,
+}
+
+impl<'a> ReviewsBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, media_type: MediaType, id: u64) -> Self {
+        Self {
+            client,
+            media_type,
+            id,
+            language: client.language.clone(),
+            page: None,
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn search(self) -> Result<ReviewsResponse<'a>, Error> {
+        PagedResponse::load(self)
+    }
+}
+
+impl PagedBuilder for ReviewsBuilder<'_> {
+    type Page = Page<Review>;
+
+    fn fetch_page(&self) -> Result<Self::Page, Error> {
+        let mut query = Vec::new();
+        if let Some(language) = &self.language {
+            query.push(("language", language.to_string()));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        let response: Page<RawReview> = match self.media_type {
+            MediaType::Movie => self.client.get_json(
+                "https://api.themoviedb.org/3/movie/{movie_id}/reviews",
+                &format!("/3/movie/{}/reviews", self.id),
+                &query,
+            )?,
+            MediaType::Tv => self.client.get_json(
+                "https://api.themoviedb.org/3/tv/{series_id}/reviews",
+                &format!("/3/tv/{}/reviews", self.id),
+                &query,
+            )?,
+        };
+        Ok(response.map(Review::from))
+    }
+
+    fn set_page(&mut self, page: i64) {
+        self.page = Some(page);
+    }
+}
+
+#[test]
+fn reviews_with_author_details() {
+    let page: Page<RawReview> = serde_json::from_str(
+        r#"{"page": 1, "total_pages": 1, "total_results": 2, "results": [
+            {"id": "a", "author": "Goddard", "author_details": {"name": "", "username": "Goddard", "avatar_path": "/https://www.gravatar.com/avatar/f248.jpg", "rating": null},
+             "content": "Pretty awesome movie.", "created_at": "2018-06-09T17:51:53.359Z", "updated_at": "2021-06-23T15:58:09.421Z"},
+            {"id": "b", "author": "Brett", "author_details": {"name": "Brett", "username": "brett", "avatar_path": null, "rating": 7.0},
+             "content": "Good.", "created_at": "not a date"}
+        ]}"#,
+    )
+    .unwrap();
+    let reviews: Vec<Review> = page.results.into_iter().map(Review::from).collect();
+    let mut client = TmdbClient::new("offline");
+    assert_eq!(reviews[0].author.name, "Goddard");
+    assert_eq!(
+        reviews[0]
+            .author
+            .avatar_url(&mut client)
+            .unwrap()
+            .as_deref(),
+        Some("https://www.gravatar.com/avatar/f248.jpg")
+    );
+    assert_eq!(reviews[0].created_at.unwrap().date.year, 2018);
+    assert_eq!(reviews[1].author.avatar_url(&mut client).unwrap(), None);
+    assert_eq!(reviews[1].author.rating, Some(7.0));
+    assert_eq!(reviews[1].created_at, None);
+    assert_eq!(
+        path_with_segment("/3/review", "5b1c/../x?y"),
+        "/3/review/5b1c%2F..%2Fx%3Fy"
+    );
+}
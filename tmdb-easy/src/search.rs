@@ -1,2 +1,3 @@
 pub(crate) mod movie;
+pub(crate) mod person;
 pub(crate) mod tv_show;
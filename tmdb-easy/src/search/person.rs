@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+use tmdb_easy_raw::types::{SearchPersonResponse200, SearchPersonResponse200Results};
+
+use crate::{
+    client::TmdbClient,
+    error::Error,
+    localization::Locale,
+    page::{PagedBuilder, PagedResponse, result_page},
+};
+
+result_page!(SearchPersonResponse200, SearchPersonResponse200Results);
+
+pub type SearchPersonResponse<'a> = PagedResponse<SearchPersonBuilder<'a>>;
+
+pub struct SearchPersonBuilder<'a> {
+    client: &'a TmdbClient,
+    query: Cow<'a, str>,
+    parameters: tmdb_easy_raw::parameter_types::SearchPersonParameter<'a>,
+}
+
+impl<'a> SearchPersonBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, query: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            client,
+            query: query.into(),
+            parameters: tmdb_easy_raw::parameter_types::SearchPersonParameter {
+                language: client.language.clone(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn include_adult(mut self, include_adult: bool) -> Self {
+        self.parameters.include_adult = Some(include_adult);
+        self
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.parameters.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_page(mut self, page: i64) -> Self {
+        self.parameters.page = Some(page);
+        self
+    }
+
+    pub fn search(self) -> Result<SearchPersonResponse<'a>, Error> {
+        PagedResponse::load(self)
+    }
+}
+
+impl PagedBuilder for SearchPersonBuilder<'_> {
+    type Page = SearchPersonResponse200;
+
+    fn fetch_page(&self) -> Result<Self::Page, Error> {
+        let mut query = vec![("query", self.query.to_string())];
+        if let Some(include_adult) = self.parameters.include_adult {
+            query.push(("include_adult", include_adult.to_string()));
+        }
+        if let Some(language) = &self.parameters.language {
+            query.push(("language", language.to_string()));
+        }
+        if let Some(page) = self.parameters.page {
+            query.push(("page", page.to_string()));
+        }
+        self.client.get_json(
+            "https://api.themoviedb.org/3/search/person",
+            "/3/search/person",
+            &query,
+        )
+    }
+
+    fn set_page(&mut self, page: i64) {
+        self.parameters.page = Some(page);
+    }
+}
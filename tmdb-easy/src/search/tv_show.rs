@@ -2,7 +2,7 @@ use std::{borrow::Cow, ops::Index};
 
 use tmdb_easy_raw::types::SearchTvResponse200Results;
 
-use crate::{client::TmdbClient, error::Error};
+use crate::{client::TmdbClient, error::Error, localization::Locale};
 
 pub struct SearchTvResponse<'a> {
     builder: SearchTvBuilder<'a>,
@@ -42,7 +42,10 @@ impl<'a> SearchTvBuilder<'a> {
         Self {
             client,
             query: query.into(),
-            parameters: Default::default(),
+            parameters: tmdb_easy_raw::parameter_types::SearchTvParameter {
+                language: client.language.clone(),
+                ..Default::default()
+            },
         }
     }
 
@@ -51,8 +54,8 @@ impl<'a> SearchTvBuilder<'a> {
         self
     }
 
-    pub fn with_language<'b: 'a>(mut self, language: &'b str) -> Self {
-        self.parameters.language = Some(language.into());
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.parameters.language = Some(language.into().to_string().into());
         self
     }
 
@@ -0,0 +1,109 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{configuration::ReferenceData, region::Region};
+
+/// An IANA timezone, like `Europe/Berlin` or `America/Argentina/Buenos_Aires`.
+/// Any well-formed name is accepted, use [`Timezone::is_known`] to check it
+/// against the zones tmdb lists.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Deserialize)]
+#[serde(try_from = "String")]
+pub struct Timezone(String);
+
+impl Timezone {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether tmdb knows this timezone, according to the vendored snapshot
+    /// of the configuration.
+    pub fn is_known(&self) -> bool {
+        self.is_known_in(ReferenceData::snapshot())
+    }
+
+    pub fn is_known_in(&self, data: &ReferenceData) -> bool {
+        data.timezones
+            .iter()
+            .any(|country| country.zones.contains(&self.0))
+    }
+
+    /// The countries using this timezone according to the vendored snapshot,
+    /// e.g. `DE` for `Europe/Berlin`.
+    pub fn regions(&self) -> impl Iterator<Item = Region> + '_ {
+        ReferenceData::snapshot()
+            .timezones
+            .iter()
+            .filter(|country| country.zones.contains(&self.0))
+            .map(|country| country.region)
+    }
+}
+
+impl Display for Timezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid timezone, expected an IANA timezone like Europe/Berlin: {0:?}")]
+pub struct InvalidTimezone(pub String);
+
+impl FromStr for Timezone {
+    type Err = InvalidTimezone;
+
+    /// Accepts names made of `/` separated parts of ASCII letters, digits,
+    /// `_`, `-` and `+`, e.g. `UTC` or `Etc/GMT+5`. The case has to match.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valid_part = |part: &str| {
+            part.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+        };
+        if s.split('/').all(valid_part) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(InvalidTimezone(s.to_string()))
+        }
+    }
+}
+
+impl TryFrom<String> for Timezone {
+    type Error = InvalidTimezone;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[test]
+fn timezone_names() {
+    let timezone: Timezone = "America/New_York".parse().unwrap();
+    assert_eq!(timezone.to_string(), "America/New_York");
+    assert!(timezone.is_known());
+    assert!(timezone.regions().any(|region| region.as_str() == "US"));
+    assert!("America/Argentina/Buenos_Aires".parse::<Timezone>().is_ok());
+    assert!("Etc/GMT+5".parse::<Timezone>().is_ok());
+
+    // Zones tmdb adds later are accepted, but unknown to the snapshot.
+    let new_zone: Timezone = "Mars/Olympus_Mons".parse().unwrap();
+    assert!(!new_zone.is_known());
+    assert_eq!(new_zone.regions().count(), 0);
+    let mut data = ReferenceData::snapshot().clone();
+    data.timezones[0]
+        .zones
+        .push("Mars/Olympus_Mons".to_string());
+    assert!(new_zone.is_known_in(&data));
+    assert!(!"europe/berlin".parse::<Timezone>().unwrap().is_known());
+
+    for invalid in [
+        "",
+        "/",
+        "Europe/",
+        "/Berlin",
+        "Europe//Berlin",
+        "Europe/Berlin?x=1",
+        "../x",
+    ] {
+        assert!(invalid.parse::<Timezone>().is_err(), "{invalid:?}");
+    }
+}
@@ -0,0 +1,202 @@
+//! Collects every known title of a movie or tv series, to decide whether a
+//! string like a release name refers to it.
+
+use crate::{
+    MediaType, MovieOrTvLike, client::TmdbClient, error::Error, localization::Localizable,
+};
+
+/// Where a title in a [`TitleSet`] comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TitleSource {
+    Original,
+    /// A translation into a language, e.g. `de-DE`.
+    Translation {
+        language: String,
+        region: String,
+    },
+    /// An alternative title in a country, e.g. a working title. The type is
+    /// free text on tmdb and often empty.
+    Alternative {
+        region: String,
+        title_type: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KnownTitle {
+    pub title: String,
+    pub source: TitleSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+struct AlternativeTitle {
+    iso_3166_1: String,
+    title: String,
+    #[serde(rename = "type")]
+    title_type: String,
+}
+
+/// Movies list their alternative titles as `titles`, tv series as
+/// `results`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+struct AlternativeTitles {
+    #[serde(alias = "results")]
+    titles: Vec<AlternativeTitle>,
+}
+
+/// Lowercases and replaces everything but letters and digits with single
+/// spaces, so `Fight.Club.1999` and `fight club (1999)` compare equal.
+fn normalize(title: &str) -> String {
+    let mut normalized = String::with_capacity(title.len());
+    for word in title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        normalized.extend(word.chars().flat_map(char::to_lowercase));
+    }
+    normalized
+}
+
+/// The original, translated and alternative titles of a movie or tv series.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TitleSet {
+    pub titles: Vec<KnownTitle>,
+}
+
+impl TitleSet {
+    pub(crate) fn fetch(
+        client: &TmdbClient,
+        item: &(impl MovieOrTvLike + ?Sized),
+    ) -> Result<Self, Error> {
+        let id = item.id();
+        let (target, source, path) = match item.media_type() {
+            MediaType::Movie => (
+                Localizable::Movie(id),
+                "https://api.themoviedb.org/3/movie/{movie_id}/alternative_titles",
+                format!("/3/movie/{id}/alternative_titles"),
+            ),
+            MediaType::Tv => (
+                Localizable::Tv(id),
+                "https://api.themoviedb.org/3/tv/{series_id}/alternative_titles",
+                format!("/3/tv/{id}/alternative_titles"),
+            ),
+        };
+        let translations = target.translations(client)?;
+        let alternative: AlternativeTitles = client.get_json(source, &path, &[])?;
+
+        let mut set = Self::default();
+        set.insert(translations.original_title, TitleSource::Original);
+        for translation in translations.translations {
+            set.insert(
+                translation.data.title,
+                TitleSource::Translation {
+                    language: translation.iso_639_1,
+                    region: translation.iso_3166_1,
+                },
+            );
+        }
+        for title in alternative.titles {
+            set.insert(
+                title.title,
+                TitleSource::Alternative {
+                    region: title.iso_3166_1,
+                    title_type: title.title_type,
+                },
+            );
+        }
+        Ok(set)
+    }
+
+    /// Adds a title, unless it is empty or already known.
+    pub fn insert(&mut self, title: String, source: TitleSource) {
+        let normalized = normalize(&title);
+        if normalized.is_empty()
+            || self
+                .titles
+                .iter()
+                .any(|known| normalize(&known.title) == normalized)
+        {
+            return;
+        }
+        self.titles.push(KnownTitle { title, source });
+    }
+
+    pub fn contains(&self, title: &str) -> bool {
+        let title = normalize(title);
+        self.titles
+            .iter()
+            .any(|known| normalize(&known.title) == title)
+    }
+
+    /// The longest known title `candidate` starts with, ignoring case and
+    /// punctuation. Release names like `Fight.Club.1999.1080p.BluRay` match
+    /// `Fight Club`. Titles have to end at a word boundary, so `Gothic` does
+    /// not match `GoT`.
+    pub fn best_match(&self, candidate: &str) -> Option<&KnownTitle> {
+        let candidate = normalize(candidate);
+        self.titles
+            .iter()
+            .map(|known| (normalize(&known.title), known))
+            .filter(|(title, _)| {
+                candidate
+                    .strip_prefix(title.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+            })
+            .max_by_key(|(title, _)| title.len())
+            .map(|(_, known)| known)
+    }
+
+    pub fn matches(&self, candidate: &str) -> bool {
+        self.best_match(candidate).is_some()
+    }
+}
+
+#[test]
+fn release_names_match_known_titles() {
+    let alternative: AlternativeTitles = serde_json::from_str(
+        r#"{"id": 1399, "results": [
+            {"iso_3166_1": "US", "title": "GoT", "type": "abbreviation"},
+            {"iso_3166_1": "DE", "title": "Game of Thrones - Das Lied von Eis und Feuer", "type": ""}
+        ]}"#,
+    )
+    .unwrap();
+    let mut set = TitleSet::default();
+    set.insert("Game of Thrones".to_string(), TitleSource::Original);
+    set.insert(
+        "game of thrones".to_string(),
+        TitleSource::Translation {
+            language: "en".to_string(),
+            region: "US".to_string(),
+        },
+    );
+    for title in alternative.titles {
+        set.insert(
+            title.title,
+            TitleSource::Alternative {
+                region: title.iso_3166_1,
+                title_type: title.title_type,
+            },
+        );
+    }
+    assert_eq!(set.titles.len(), 3);
+    assert!(set.contains("GAME OF THRONES"));
+    assert_eq!(
+        set.best_match("Game.of.Thrones.S01E01.720p.HDTV")
+            .unwrap()
+            .source,
+        TitleSource::Original
+    );
+    assert_eq!(
+        set.best_match("Game of Thrones - Das Lied von Eis und Feuer S01")
+            .unwrap()
+            .title,
+        "Game of Thrones - Das Lied von Eis und Feuer"
+    );
+    assert!(set.matches("GoT.S08E06"));
+    assert!(!set.matches("Gothic.2021"));
+}
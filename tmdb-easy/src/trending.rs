@@ -0,0 +1,168 @@
+use std::{borrow::Cow, fmt::Display, marker::PhantomData};
+
+use tmdb_easy_raw::types::{
+    TrendingMoviesResponse200Results, TrendingPeopleResponse200Results,
+    TrendingTvResponse200Results,
+};
+
+use crate::{
+    MovieOrTvLike,
+    client::TmdbClient,
+    error::Error,
+    localization::Locale,
+    page::{Page, PagedBuilder, PagedResponse},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeWindow {
+    #[default]
+    Day,
+    Week,
+}
+
+impl Display for TimeWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeWindow::Day => write!(f, "day"),
+            TimeWindow::Week => write!(f, "week"),
+        }
+    }
+}
+
+/// A result of [`TmdbClient::trending_all`], which mixes movies, tv series and
+/// people.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum TrendingItem {
+    Movie(TrendingMoviesResponse200Results),
+    Tv(TrendingTvResponse200Results),
+    Person(TrendingPeopleResponse200Results),
+}
+
+impl TrendingItem {
+    pub fn id(&self) -> u64 {
+        match self {
+            TrendingItem::Movie(it) => it.id as _,
+            TrendingItem::Tv(it) => it.id as _,
+            TrendingItem::Person(it) => it.id as _,
+        }
+    }
+
+    /// Returns the movie or tv series, so the poster helpers can be used.
+    /// People have no poster and return `None`.
+    pub fn as_movie_or_tv(&self) -> Option<&dyn MovieOrTvLike> {
+        match self {
+            TrendingItem::Movie(it) => Some(it),
+            TrendingItem::Tv(it) => Some(it),
+            TrendingItem::Person(_) => None,
+        }
+    }
+}
+
+/// The kinds of results, that can be trending.
+pub trait TrendingMedia: serde::de::DeserializeOwned {
+    /// The generated endpoint, e.g.
+    /// `https://api.themoviedb.org/3/trending/movie/{time_window}`.
+    const SOURCE: &'static str;
+    /// The path without the time window, e.g. `/3/trending/movie`.
+    const PATH: &'static str;
+}
+
+impl TrendingMedia for TrendingItem {
+    const SOURCE: &'static str = "https://api.themoviedb.org/3/trending/all/{time_window}";
+    const PATH: &'static str = "/3/trending/all";
+}
+
+impl TrendingMedia for TrendingMoviesResponse200Results {
+    const SOURCE: &'static str = "https://api.themoviedb.org/3/trending/movie/{time_window}";
+    const PATH: &'static str = "/3/trending/movie";
+}
+
+impl TrendingMedia for TrendingTvResponse200Results {
+    const SOURCE: &'static str = "https://api.themoviedb.org/3/trending/tv/{time_window}";
+    const PATH: &'static str = "/3/trending/tv";
+}
+
+impl TrendingMedia for TrendingPeopleResponse200Results {
+    const SOURCE: &'static str = "https://api.themoviedb.org/3/trending/person/{time_window}";
+    const PATH: &'static str = "/3/trending/person";
+}
+
+pub type TrendingResponse<'a, T> = PagedResponse<TrendingBuilder<'a, T>>;
+
+pub struct TrendingBuilder<'a, T> {
+    client: &'a TmdbClient,
+    time_window: TimeWindow,
+    language: Option<Cow<'a, str>>,
+    page: Option<i64>,
+    media: PhantomData<T>,
+}
+
+impl<'a, T: TrendingMedia> TrendingBuilder<'a, T> {
+    pub fn new(client: &'a TmdbClient, time_window: TimeWindow) -> Self {
+        Self {
+            client,
+            time_window,
+            language: client.language.clone(),
+            page: None,
+            media: PhantomData,
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn search(self) -> Result<TrendingResponse<'a, T>, Error> {
+        PagedResponse::load(self)
+    }
+}
+
+impl<T: TrendingMedia> PagedBuilder for TrendingBuilder<'_, T> {
+    type Page = Page<T>;
+
+    fn fetch_page(&self) -> Result<Self::Page, Error> {
+        let mut query = Vec::new();
+        if let Some(language) = &self.language {
+            query.push(("language", language.to_string()));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        self.client.get_json(
+            T::SOURCE,
+            &format!("{}/{}", T::PATH, self.time_window),
+            &query,
+        )
+    }
+
+    fn set_page(&mut self, page: i64) {
+        self.page = Some(page);
+    }
+}
+
+#[test]
+fn trending_item_from_media_type() {
+    let item: TrendingItem =
+        serde_json::from_str(r#"{"media_type": "tv", "id": 1399, "name": "Game of Thrones"}"#)
+            .unwrap();
+    let TrendingItem::Tv(tv) = &item else {
+        panic!("expected a tv series, got {item:?}");
+    };
+    assert_eq!(tv.name, "Game of Thrones");
+    assert_eq!(item.id(), 1399);
+    assert!(item.as_movie_or_tv().is_some());
+
+    let page: Page<TrendingMoviesResponse200Results> = serde_json::from_str(
+        r#"{"page": 2, "total_pages": 500, "total_results": 10000, "results": [{"id": 550, "title": "Fight Club", "vote_average": 8.4}]}"#,
+    )
+    .unwrap();
+    assert_eq!(page.total_pages, 500);
+    assert_eq!(page.results[0].title, "Fight Club");
+}
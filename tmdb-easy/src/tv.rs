@@ -1,33 +1,667 @@
-use tmdb_easy_raw::types::{SearchTvResponse200Results, TvSeasonDetailsResponse200};
+use std::{borrow::Cow, fmt::Display};
 
-use crate::{MovieOrTvLike, client::TmdbClient, error::Error};
+use tmdb_easy_raw::types::{
+    DiscoverTvResponse200Results, SearchTvResponse200Results, TrendingTvResponse200Results,
+    TvEpisodeCreditsResponse200, TvEpisodeDetailsResponse200, TvEpisodeExternalIdsResponse200,
+    TvEpisodeImagesResponse200, TvEpisodeVideosResponse200, TvSeasonAggregateCreditsResponse200,
+    TvSeasonCreditsResponse200, TvSeasonDetailsResponse200, TvSeasonDetailsResponse200Episodes,
+    TvSeasonExternalIdsResponse200, TvSeasonImagesResponse200, TvSeasonVideosResponse200,
+    TvSeriesAggregateCreditsResponse200, TvSeriesAiringTodayListResponse200Results,
+    TvSeriesContentRatingsResponse200, TvSeriesCreditsResponse200, TvSeriesDetailsResponse200,
+    TvSeriesExternalIdsResponse200, TvSeriesImagesResponse200, TvSeriesKeywordsResponse200,
+    TvSeriesOnTheAirListResponse200Results, TvSeriesPopularListResponse200Results,
+    TvSeriesTopRatedListResponse200Results, TvSeriesVideosResponse200,
+};
+
+use crate::{
+    MediaType, MovieOrTvLike, certification::ContentRatings, client::TmdbClient,
+    episode_group::EpisodeGroupSummary, error::Error, external_ids::ExternalIds,
+    impl_movie_or_tv_like, keyword::Keywords, localization::Locale, review::ReviewsBuilder,
+    video::Videos, watch_provider::WatchProviders,
+};
+
+/// The production status of a series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeriesStatus {
+    ReturningSeries = 0,
+    Planned = 1,
+    InProduction = 2,
+    Ended = 3,
+    Canceled = 4,
+    Pilot = 5,
+}
+
+impl Display for SeriesStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeriesType {
+    Documentary = 0,
+    News = 1,
+    Miniseries = 2,
+    Reality = 3,
+    Scripted = 4,
+    TalkShow = 5,
+    Video = 6,
+}
+
+impl Display for SeriesType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
 
 pub trait TvLike: MovieOrTvLike {
+    /// Loads a season in the default language of the client.
     fn season(
         &self,
         client: &TmdbClient,
         season: u32,
     ) -> Result<TvSeasonDetailsResponse200, Error> {
-        Ok(
-            tmdb_easy_raw::parametrized_functions::tv_season_details_with_parameter(
-                &client.client,
-                &client.api_key,
-                self.id() as _,
-                season as _,
-                Default::default(),
-            )?,
+        client.get_details(
+            "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}",
+            &format!("/3/tv/{}/season/{season}", self.id()),
+            client.language.clone(),
+            &[],
         )
     }
+
+    /// Loads an episode in the default language of the client.
+    fn episode(
+        &self,
+        client: &TmdbClient,
+        season: u32,
+        episode: u32,
+    ) -> Result<TvEpisodeDetailsResponse200, Error> {
+        client.get_details(
+            "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}/episode/{episode_number}",
+            &format!("/3/tv/{}/season/{season}/episode/{episode}", self.id()),
+            client.language.clone(),
+            &[],
+        )
+    }
+
+    /// The numbers of all seasons of this series, including specials as
+    /// season 0. Loads the series details, if they are not already known.
+    fn season_numbers(&self, client: &TmdbClient) -> Result<Vec<u32>, Error> {
+        Ok(season_numbers(&client.tv(self.id()).load()?.details))
+    }
+
+    /// Loads every season and returns all of their episodes in order.
+    fn all_episodes(
+        &self,
+        client: &TmdbClient,
+    ) -> Result<Vec<TvSeasonDetailsResponse200Episodes>, Error> {
+        let mut episodes = Vec::new();
+        for season in self.season_numbers(client)? {
+            episodes.append(&mut self.season(client, season)?.episodes);
+        }
+        Ok(episodes)
+    }
+
+    /// The alternative orderings of the episodes, like the DVD order.
+    fn episode_groups(&self, client: &TmdbClient) -> Result<Vec<EpisodeGroupSummary>, Error> {
+        EpisodeGroupSummary::fetch(client, self.id())
+    }
+}
+
+fn season_numbers(details: &TvSeriesDetailsResponse200) -> Vec<u32> {
+    details
+        .seasons
+        .iter()
+        .map(|s| s.season_number as u32)
+        .collect()
 }
 
-impl MovieOrTvLike for SearchTvResponse200Results {
+/// The details of a tv series, together with all the sections requested with
+/// the [`TvSeriesBuilder`]. Sections, that were not requested, are `None`.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct TvSeriesDetails {
+    #[serde(flatten)]
+    pub details: TvSeriesDetailsResponse200,
+    pub credits: Option<TvSeriesCreditsResponse200>,
+    pub aggregate_credits: Option<TvSeriesAggregateCreditsResponse200>,
+    pub videos: Option<TvSeriesVideosResponse200>,
+    pub images: Option<TvSeriesImagesResponse200>,
+    pub keywords: Option<TvSeriesKeywordsResponse200>,
+    pub content_ratings: Option<TvSeriesContentRatingsResponse200>,
+    pub external_ids: Option<TvSeriesExternalIdsResponse200>,
+}
+
+impl MovieOrTvLike for TvSeriesDetails {
     fn id(&self) -> u64 {
-        self.id as _
+        self.details.id as _
+    }
+
+    fn media_type(&self) -> MediaType {
+        MediaType::Tv
     }
 
     fn poster_path(&self, _client: &TmdbClient) -> Result<String, Error> {
-        Ok(self.poster_path.clone())
+        Ok(self.details.poster_path.clone())
+    }
+}
+
+impl TvLike for TvSeriesDetails {
+    fn season_numbers(&self, _client: &TmdbClient) -> Result<Vec<u32>, Error> {
+        Ok(season_numbers(&self.details))
+    }
+}
+
+impl_movie_or_tv_like!(TvSeriesDetailsResponse200, MediaType::Tv);
+
+impl TvLike for TvSeriesDetailsResponse200 {
+    fn season_numbers(&self, _client: &TmdbClient) -> Result<Vec<u32>, Error> {
+        Ok(season_numbers(self))
+    }
+}
+
+/// The details of a season, together with all the sections requested with
+/// the [`TvSeasonBuilder`].
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct TvSeasonDetails {
+    #[serde(flatten)]
+    pub details: TvSeasonDetailsResponse200,
+    pub credits: Option<TvSeasonCreditsResponse200>,
+    pub aggregate_credits: Option<TvSeasonAggregateCreditsResponse200>,
+    pub videos: Option<TvSeasonVideosResponse200>,
+    pub images: Option<TvSeasonImagesResponse200>,
+    pub external_ids: Option<TvSeasonExternalIdsResponse200>,
+}
+
+/// The details of an episode, together with all the sections requested with
+/// the [`TvEpisodeBuilder`].
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct TvEpisodeDetails {
+    #[serde(flatten)]
+    pub details: TvEpisodeDetailsResponse200,
+    pub credits: Option<TvEpisodeCreditsResponse200>,
+    pub videos: Option<TvEpisodeVideosResponse200>,
+    pub images: Option<TvEpisodeImagesResponse200>,
+    pub external_ids: Option<TvEpisodeExternalIdsResponse200>,
+}
+
+/// Loads the details of a tv series. Additional sections are requested in the
+/// same request, using `append_to_response`. Use [`Self::season`] to navigate
+/// to a season of this series.
+pub struct TvSeriesBuilder<'a> {
+    client: &'a TmdbClient,
+    id: u64,
+    language: Option<Cow<'a, str>>,
+    append_to_response: Vec<&'static str>,
+}
+
+impl<'a> TvSeriesBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, id: u64) -> Self {
+        Self {
+            client,
+            id,
+            language: client.language.clone(),
+            append_to_response: Vec::new(),
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_credits(self) -> Self {
+        self.append("credits")
+    }
+
+    /// Credits of all seasons combined, instead of only the latest season.
+    pub fn with_aggregate_credits(self) -> Self {
+        self.append("aggregate_credits")
+    }
+
+    pub fn with_videos(self) -> Self {
+        self.append("videos")
+    }
+
+    pub fn with_images(self) -> Self {
+        self.append("images")
+    }
+
+    pub fn with_keywords(self) -> Self {
+        self.append("keywords")
+    }
+
+    pub fn with_content_ratings(self) -> Self {
+        self.append("content_ratings")
+    }
+
+    pub fn with_external_ids(self) -> Self {
+        self.append("external_ids")
+    }
+
+    fn append(mut self, section: &'static str) -> Self {
+        if !self.append_to_response.contains(&section) {
+            self.append_to_response.push(section);
+        }
+        self
+    }
+
+    /// Navigates to a season of this series. Keeps the language, but not the
+    /// requested sections.
+    pub fn season(&self, season: u32) -> TvSeasonBuilder<'a> {
+        let mut builder = TvSeasonBuilder::new(self.client, self.id, season);
+        builder.language = self.language.clone();
+        builder
+    }
+
+    pub fn load(self) -> Result<TvSeriesDetails, Error> {
+        self.client.get_details(
+            "https://api.themoviedb.org/3/tv/{series_id}",
+            &format!("/3/tv/{}", self.id),
+            self.language,
+            &self.append_to_response,
+        )
+    }
+
+    /// The videos of the series, like [`crate::movie::MovieBuilder::videos`].
+    pub fn videos(self) -> Result<Videos, Error> {
+        Videos::fetch(
+            self.client,
+            "https://api.themoviedb.org/3/tv/{series_id}/videos",
+            &format!("/3/tv/{}/videos", self.id),
+            self.language.as_deref(),
+        )
+    }
+
+    /// The certifications of the series, in every region.
+    pub fn content_ratings(self) -> Result<ContentRatings, Error> {
+        Ok(
+            tmdb_easy_raw::parametrized_functions::tv_series_content_ratings(
+                &self.client.client,
+                &self.client.api_key,
+                self.id as _,
+            )?
+            .into(),
+        )
+    }
+
+    /// Where the series can be streamed, rented or bought, in every region.
+    pub fn watch_providers(self) -> Result<WatchProviders, Error> {
+        self.client.get_json(
+            "https://api.themoviedb.org/3/tv/{series_id}/watch/providers",
+            &format!("/3/tv/{}/watch/providers", self.id),
+            &[],
+        )
+    }
+
+    pub fn keywords(self) -> Result<Keywords, Error> {
+        Ok(tmdb_easy_raw::parametrized_functions::tv_series_keywords(
+            &self.client.client,
+            &self.client.api_key,
+            self.id as _,
+        )?
+        .into())
+    }
+
+    /// Lists the reviews of the series, page by page.
+    pub fn reviews(self) -> ReviewsBuilder<'a> {
+        let mut builder = ReviewsBuilder::new(self.client, MediaType::Tv, self.id);
+        builder.language = self.language;
+        builder
+    }
+
+    /// The ids of the series on other sites, like IMDb or TheTVDB.
+    pub fn external_ids(self) -> Result<ExternalIds, Error> {
+        ExternalIds::fetch(
+            self.client,
+            "https://api.themoviedb.org/3/tv/{series_id}/external_ids",
+            &format!("/3/tv/{}/external_ids", self.id),
+        )
     }
 }
 
+pub struct TvSeasonBuilder<'a> {
+    client: &'a TmdbClient,
+    series_id: u64,
+    season: u32,
+    language: Option<Cow<'a, str>>,
+    append_to_response: Vec<&'static str>,
+}
+
+impl<'a> TvSeasonBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, series_id: u64, season: u32) -> Self {
+        Self {
+            client,
+            series_id,
+            season,
+            language: client.language.clone(),
+            append_to_response: Vec::new(),
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_credits(self) -> Self {
+        self.append("credits")
+    }
+
+    pub fn with_aggregate_credits(self) -> Self {
+        self.append("aggregate_credits")
+    }
+
+    pub fn with_videos(self) -> Self {
+        self.append("videos")
+    }
+
+    pub fn with_images(self) -> Self {
+        self.append("images")
+    }
+
+    pub fn with_external_ids(self) -> Self {
+        self.append("external_ids")
+    }
+
+    fn append(mut self, section: &'static str) -> Self {
+        if !self.append_to_response.contains(&section) {
+            self.append_to_response.push(section);
+        }
+        self
+    }
+
+    /// Navigates to an episode of this season. Keeps the language, but not the
+    /// requested sections.
+    pub fn episode(&self, episode: u32) -> TvEpisodeBuilder<'a> {
+        let mut builder = TvEpisodeBuilder::new(self.client, self.series_id, self.season, episode);
+        builder.language = self.language.clone();
+        builder
+    }
+
+    pub fn load(self) -> Result<TvSeasonDetails, Error> {
+        self.client.get_details(
+            "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}",
+            &format!("/3/tv/{}/season/{}", self.series_id, self.season),
+            self.language,
+            &self.append_to_response,
+        )
+    }
+
+    /// The videos of the season, like [`crate::movie::MovieBuilder::videos`].
+    pub fn videos(self) -> Result<Videos, Error> {
+        Videos::fetch(
+            self.client,
+            "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}/videos",
+            &format!("/3/tv/{}/season/{}/videos", self.series_id, self.season),
+            self.language.as_deref(),
+        )
+    }
+
+    /// Where the season can be streamed, rented or bought, in every region.
+    pub fn watch_providers(self) -> Result<WatchProviders, Error> {
+        let mut query = Vec::new();
+        if let Some(language) = self.language {
+            query.push(("language", language.into_owned()));
+        }
+        self.client.get_json(
+            "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}/watch/providers",
+            &format!(
+                "/3/tv/{}/season/{}/watch/providers",
+                self.series_id, self.season
+            ),
+            &query,
+        )
+    }
+
+    pub fn external_ids(self) -> Result<ExternalIds, Error> {
+        ExternalIds::fetch(
+            self.client,
+            "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}/external_ids",
+            &format!(
+                "/3/tv/{}/season/{}/external_ids",
+                self.series_id, self.season
+            ),
+        )
+    }
+}
+
+pub struct TvEpisodeBuilder<'a> {
+    client: &'a TmdbClient,
+    series_id: u64,
+    season: u32,
+    episode: u32,
+    language: Option<Cow<'a, str>>,
+    append_to_response: Vec<&'static str>,
+}
+
+impl<'a> TvEpisodeBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, series_id: u64, season: u32, episode: u32) -> Self {
+        Self {
+            client,
+            series_id,
+            season,
+            episode,
+            language: client.language.clone(),
+            append_to_response: Vec::new(),
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    pub fn with_credits(self) -> Self {
+        self.append("credits")
+    }
+
+    pub fn with_videos(self) -> Self {
+        self.append("videos")
+    }
+
+    pub fn with_images(self) -> Self {
+        self.append("images")
+    }
+
+    pub fn with_external_ids(self) -> Self {
+        self.append("external_ids")
+    }
+
+    fn append(mut self, section: &'static str) -> Self {
+        if !self.append_to_response.contains(&section) {
+            self.append_to_response.push(section);
+        }
+        self
+    }
+
+    pub fn load(self) -> Result<TvEpisodeDetails, Error> {
+        self.client.get_details(
+            "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}/episode/{episode_number}",
+            &format!(
+                "/3/tv/{}/season/{}/episode/{}",
+                self.series_id, self.season, self.episode
+            ),
+            self.language,
+            &self.append_to_response,
+        )
+    }
+
+    /// The videos of the episode, like [`crate::movie::MovieBuilder::videos`].
+    pub fn videos(self) -> Result<Videos, Error> {
+        Videos::fetch(
+            self.client,
+            "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}/episode/{episode_number}/videos",
+            &format!(
+                "/3/tv/{}/season/{}/episode/{}/videos",
+                self.series_id, self.season, self.episode
+            ),
+            self.language.as_deref(),
+        )
+    }
+
+    pub fn external_ids(self) -> Result<ExternalIds, Error> {
+        ExternalIds::fetch(
+            self.client,
+            "https://api.themoviedb.org/3/tv/{series_id}/season/{season_number}/episode/{episode_number}/external_ids",
+            &format!(
+                "/3/tv/{}/season/{}/episode/{}/external_ids",
+                self.series_id, self.season, self.episode
+            ),
+        )
+    }
+}
+
+impl_movie_or_tv_like!(SearchTvResponse200Results, MediaType::Tv);
+
 impl TvLike for SearchTvResponse200Results {}
+
+impl_movie_or_tv_like!(DiscoverTvResponse200Results, MediaType::Tv);
+
+impl TvLike for DiscoverTvResponse200Results {}
+
+impl_movie_or_tv_like!(TrendingTvResponse200Results, MediaType::Tv);
+
+impl TvLike for TrendingTvResponse200Results {}
+
+impl_movie_or_tv_like!(TvSeriesAiringTodayListResponse200Results, MediaType::Tv);
+
+impl TvLike for TvSeriesAiringTodayListResponse200Results {}
+
+impl_movie_or_tv_like!(TvSeriesOnTheAirListResponse200Results, MediaType::Tv);
+
+impl TvLike for TvSeriesOnTheAirListResponse200Results {}
+
+impl_movie_or_tv_like!(TvSeriesPopularListResponse200Results, MediaType::Tv);
+
+impl TvLike for TvSeriesPopularListResponse200Results {}
+
+impl_movie_or_tv_like!(TvSeriesTopRatedListResponse200Results, MediaType::Tv);
+
+impl TvLike for TvSeriesTopRatedListResponse200Results {}
+
+#[test]
+fn tv_builders_and_appended_sections() {
+    let client = TmdbClient::new("offline").with_language("de-DE".parse::<Locale>().unwrap());
+    let series = client
+        .tv(1399)
+        .with_credits()
+        .with_aggregate_credits()
+        .with_credits();
+    assert_eq!(series.append_to_response, ["credits", "aggregate_credits"]);
+    let season = series.season(1).with_external_ids();
+    assert_eq!(
+        (season.series_id, season.season, season.language.as_deref()),
+        (1399, 1, Some("de-DE"))
+    );
+    assert_eq!(season.append_to_response, ["external_ids"]);
+    let episode = season.episode(2);
+    assert_eq!(
+        (episode.season, episode.episode, episode.language.as_deref()),
+        (1, 2, Some("de-DE"))
+    );
+    assert!(episode.append_to_response.is_empty());
+
+    let series: TvSeriesDetails = serde_json::from_str(
+        r#"{
+            "id": 1399,
+            "name": "Game of Thrones",
+            "seasons": [{"season_number": 0}, {"season_number": 1, "vote_average": 8.5},
+                {"season_number": 2, "vote_average": 7.9}],
+            "aggregate_credits": {"cast": [{"id": 22970, "name": "Peter Dinklage",
+                "roles": [{"character": "Tyrion Lannister", "episode_count": 67}]}], "crew": []},
+            "external_ids": {"imdb_id": "tt0944947", "tvdb_id": 121361}
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(series.details.name, "Game of Thrones");
+    assert_eq!(series.season_numbers(&client).unwrap(), [0, 1, 2]);
+    assert_eq!(series.details.seasons[1].vote_average, 8.5);
+    assert_eq!(
+        series.aggregate_credits.as_ref().unwrap().cast[0].roles[0].character,
+        "Tyrion Lannister"
+    );
+    assert_eq!(series.external_ids.as_ref().unwrap().tvdb_id, 121361);
+    assert!(series.credits.is_none());
+
+    let episode: TvEpisodeDetails = serde_json::from_str(
+        r#"{"id": 63056, "name": "Winter Is Coming", "season_number": 1, "episode_number": 1,
+            "credits": {"cast": [], "crew": [], "guest_stars": [{"id": 1, "name": "Guest"}]}}"#,
+    )
+    .unwrap();
+    assert_eq!(episode.details.episode_number, 1);
+    assert_eq!(episode.credits.unwrap().guest_stars[0].name, "Guest");
+    assert!(episode.videos.is_none());
+}
+
+#[test]
+fn all_episodes_in_season_order() {
+    /// A series, that answers season requests from fixtures.
+    struct Fixture {
+        series: TvSeriesDetails,
+        seasons: Vec<TvSeasonDetails>,
+    }
+
+    impl MovieOrTvLike for Fixture {
+        fn id(&self) -> u64 {
+            self.series.id()
+        }
+
+        fn media_type(&self) -> MediaType {
+            MediaType::Tv
+        }
+
+        fn poster_path(&self, client: &TmdbClient) -> Result<String, Error> {
+            self.series.poster_path(client)
+        }
+    }
+
+    impl TvLike for Fixture {
+        fn season(
+            &self,
+            _client: &TmdbClient,
+            season: u32,
+        ) -> Result<TvSeasonDetailsResponse200, Error> {
+            Ok(self
+                .seasons
+                .iter()
+                .find(|s| s.details.season_number == season as i64)
+                .expect("the fixture has every season")
+                .details
+                .clone())
+        }
+
+        fn season_numbers(&self, client: &TmdbClient) -> Result<Vec<u32>, Error> {
+            self.series.season_numbers(client)
+        }
+    }
+
+    let season = |json: &str| -> TvSeasonDetails { serde_json::from_str(json).unwrap() };
+    let fixture = Fixture {
+        series: serde_json::from_str(
+            r#"{"id": 1399, "seasons": [{"season_number": 0}, {"season_number": 1}]}"#,
+        )
+        .unwrap(),
+        seasons: vec![
+            season(
+                r#"{"season_number": 1, "episodes": [
+                    {"season_number": 1, "episode_number": 1, "name": "Winter Is Coming"},
+                    {"season_number": 1, "episode_number": 2, "name": "The Kingsroad"}]}"#,
+            ),
+            season(
+                r#"{"season_number": 0, "episodes": [
+                    {"season_number": 0, "episode_number": 1, "name": "Inside the Pilot"}]}"#,
+            ),
+        ],
+    };
+    let client = TmdbClient::new("offline");
+    let names: Vec<_> = fixture
+        .all_episodes(&client)
+        .unwrap()
+        .into_iter()
+        .map(|episode| episode.name)
+        .collect();
+    assert_eq!(
+        names,
+        ["Inside the Pilot", "Winter Is Coming", "The Kingsroad"]
+    );
+}
@@ -0,0 +1,231 @@
+use std::{cmp::Reverse, fmt::Display};
+
+use tmdb_easy_raw::types::{
+    MovieVideosResponse200, TvEpisodeVideosResponse200, TvSeasonVideosResponse200,
+    TvSeriesVideosResponse200,
+};
+
+use crate::{client::TmdbClient, error::Error};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VideoSite {
+    YouTube,
+    Vimeo,
+    Other(String),
+}
+
+impl From<String> for VideoSite {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "YouTube" => VideoSite::YouTube,
+            "Vimeo" => VideoSite::Vimeo,
+            _ => VideoSite::Other(value),
+        }
+    }
+}
+
+impl Display for VideoSite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VideoSite::YouTube => write!(f, "YouTube"),
+            VideoSite::Vimeo => write!(f, "Vimeo"),
+            VideoSite::Other(site) => write!(f, "{site}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VideoType {
+    Trailer,
+    Teaser,
+    Clip,
+    Featurette,
+    BehindTheScenes,
+    Bloopers,
+    OpeningCredits,
+    Recap,
+    Other(String),
+}
+
+impl From<String> for VideoType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "Trailer" => VideoType::Trailer,
+            "Teaser" => VideoType::Teaser,
+            "Clip" => VideoType::Clip,
+            "Featurette" => VideoType::Featurette,
+            "Behind the Scenes" => VideoType::BehindTheScenes,
+            "Bloopers" => VideoType::Bloopers,
+            "Opening Credits" => VideoType::OpeningCredits,
+            "Recap" => VideoType::Recap,
+            _ => VideoType::Other(value),
+        }
+    }
+}
+
+impl Display for VideoType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VideoType::Trailer => write!(f, "Trailer"),
+            VideoType::Teaser => write!(f, "Teaser"),
+            VideoType::Clip => write!(f, "Clip"),
+            VideoType::Featurette => write!(f, "Featurette"),
+            VideoType::BehindTheScenes => write!(f, "Behind the Scenes"),
+            VideoType::Bloopers => write!(f, "Bloopers"),
+            VideoType::OpeningCredits => write!(f, "Opening Credits"),
+            VideoType::Recap => write!(f, "Recap"),
+            VideoType::Other(video_type) => write!(f, "{video_type}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Video {
+    pub id: String,
+    pub name: String,
+    /// The id of the video on its site.
+    pub key: String,
+    pub site: VideoSite,
+    pub video_type: VideoType,
+    /// Published by the studio or network.
+    pub official: bool,
+    pub language: String,
+    pub region: String,
+    /// Vertical resolution, e.g. `1080`.
+    pub size: i64,
+    pub published_at: String,
+}
+
+impl Video {
+    /// The url to watch the video on its site. Returns `None` for sites other
+    /// than YouTube and Vimeo.
+    pub fn watch_url(&self) -> Option<String> {
+        match self.site {
+            VideoSite::YouTube => Some(format!("https://www.youtube.com/watch?v={}", self.key)),
+            VideoSite::Vimeo => Some(format!("https://vimeo.com/{}", self.key)),
+            VideoSite::Other(_) => None,
+        }
+    }
+
+    /// The url of an embeddable player.
+    pub fn embed_url(&self) -> Option<String> {
+        match self.site {
+            VideoSite::YouTube => Some(format!("https://www.youtube.com/embed/{}", self.key)),
+            VideoSite::Vimeo => Some(format!("https://player.vimeo.com/video/{}", self.key)),
+            VideoSite::Other(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Videos {
+    pub results: Vec<Video>,
+}
+
+impl Videos {
+    pub(crate) fn fetch(
+        client: &TmdbClient,
+        source: &'static str,
+        path: &str,
+        language: Option<&str>,
+    ) -> Result<Self, Error> {
+        // Without `include_video_language` tmdb only returns videos in the
+        // requested language, which leaves nothing to fall back to.
+        let mut query = Vec::new();
+        if let Some(language) = language {
+            let code = language.split('-').next().unwrap_or(language);
+            query.push(("language", language.to_string()));
+            query.push(("include_video_language", format!("{code},en,null")));
+        }
+        let response: MovieVideosResponse200 = client.get_json(source, path, &query)?;
+        Ok(response.into())
+    }
+
+    pub fn trailers(&self) -> impl Iterator<Item = &Video> {
+        self.results
+            .iter()
+            .filter(|video| video.video_type == VideoType::Trailer)
+    }
+
+    /// Picks the trailer to show. Official videos are preferred over fan
+    /// uploads, trailers over teasers, then the earliest language in
+    /// `languages` (ISO 639-1, e.g. `["de", "en"]`) and finally the most
+    /// recent upload. Only videos with a playable url are considered.
+    pub fn best_trailer(&self, languages: &[&str]) -> Option<&Video> {
+        self.results
+            .iter()
+            .filter(|video| matches!(video.video_type, VideoType::Trailer | VideoType::Teaser))
+            .filter(|video| video.watch_url().is_some())
+            .min_by_key(|video| {
+                let language = languages
+                    .iter()
+                    .position(|language| *language == video.language)
+                    .unwrap_or(languages.len());
+                (
+                    !video.official,
+                    video.video_type != VideoType::Trailer,
+                    language,
+                    Reverse(video.published_at.as_str()),
+                )
+            })
+    }
+}
+
+macro_rules! videos_from {
+    ($($response:ty),* $(,)?) => {
+        $(
+            impl From<$response> for Videos {
+                fn from(value: $response) -> Self {
+                    Self {
+                        results: value
+                            .results
+                            .into_iter()
+                            .map(|video| Video {
+                                id: video.id,
+                                name: video.name,
+                                key: video.key,
+                                site: video.site.into(),
+                                video_type: video.r#type.into(),
+                                official: video.official,
+                                language: video.iso_639_1,
+                                region: video.iso_3166_1,
+                                size: video.size,
+                                published_at: video.published_at,
+                            })
+                            .collect(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+videos_from!(
+    MovieVideosResponse200,
+    TvSeriesVideosResponse200,
+    TvSeasonVideosResponse200,
+    TvEpisodeVideosResponse200,
+);
+
+#[test]
+fn best_trailer_selection() {
+    let videos: MovieVideosResponse200 = serde_json::from_str(
+        r#"{"id": 550, "results": [
+            {"key": "a", "site": "YouTube", "type": "Teaser", "official": true, "iso_639_1": "de", "published_at": "2020-01-01 00:00:00 UTC"},
+            {"key": "b", "site": "YouTube", "type": "Trailer", "official": true, "iso_639_1": "en", "published_at": "2019-01-01 00:00:00 UTC"},
+            {"key": "c", "site": "Vimeo", "type": "Trailer", "official": true, "iso_639_1": "de", "published_at": "2018-01-01 00:00:00 UTC"},
+            {"key": "d", "site": "YouTube", "type": "Trailer", "official": false, "iso_639_1": "de", "published_at": "2021-01-01 00:00:00 UTC"},
+            {"key": "e", "site": "Dailymotion", "type": "Trailer", "official": true, "iso_639_1": "de"}
+        ]}"#,
+    )
+    .unwrap();
+    let videos = Videos::from(videos);
+    let best = videos.best_trailer(&["de", "en"]).unwrap();
+    assert_eq!(best.key, "c");
+    assert_eq!(
+        best.embed_url().unwrap(),
+        "https://player.vimeo.com/video/c"
+    );
+    assert_eq!(videos.best_trailer(&["en"]).unwrap().key, "b");
+    assert_eq!(videos.trailers().count(), 4);
+}
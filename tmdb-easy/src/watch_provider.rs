@@ -0,0 +1,237 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use image::DynamicImage;
+use tmdb_easy_raw::parameter_types::{
+    WatchProviderTvListParameter, WatchProvidersAvailableRegionsParameter,
+    WatchProvidersMovieListParameter,
+};
+
+use crate::{
+    MediaType,
+    client::{ImageKind, TmdbClient},
+    discover::MonetizationType,
+    error::Error,
+    id::WatchProviderId,
+    localization::Locale,
+    region::Region,
+};
+
+/// A streaming, rental or purchase service.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct WatchProvider {
+    #[serde(rename = "provider_id")]
+    pub id: WatchProviderId,
+    #[serde(rename = "provider_name", default)]
+    pub name: String,
+    #[serde(default)]
+    pub logo_path: String,
+    /// Lower values are shown first on tmdb.
+    #[serde(default)]
+    pub display_priority: i64,
+}
+
+impl WatchProvider {
+    pub fn logo(&self, client: &mut TmdbClient) -> Result<DynamicImage, Error> {
+        client.resolve_image_path(self.logo_path.clone(), ImageKind::Logo)
+    }
+}
+
+/// Where a title is available in a single region.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct RegionWatchProviders {
+    /// Link to the tmdb page listing the offers, tmdb asks to attribute
+    /// JustWatch when displaying this data.
+    pub link: String,
+    pub flatrate: Vec<WatchProvider>,
+    pub free: Vec<WatchProvider>,
+    pub ads: Vec<WatchProvider>,
+    pub rent: Vec<WatchProvider>,
+    pub buy: Vec<WatchProvider>,
+}
+
+impl RegionWatchProviders {
+    pub fn get(&self, monetization: MonetizationType) -> &[WatchProvider] {
+        match monetization {
+            MonetizationType::Flatrate => &self.flatrate,
+            MonetizationType::Free => &self.free,
+            MonetizationType::Ads => &self.ads,
+            MonetizationType::Rent => &self.rent,
+            MonetizationType::Buy => &self.buy,
+        }
+    }
+
+    /// All providers, together with how they offer the title.
+    pub fn iter(&self) -> impl Iterator<Item = (MonetizationType, &WatchProvider)> {
+        MonetizationType::ALL
+            .into_iter()
+            .flat_map(move |monetization| {
+                self.get(monetization)
+                    .iter()
+                    .map(move |provider| (monetization, provider))
+            })
+    }
+}
+
+/// The watch providers of a movie, tv series or season in every region they
+/// are known for.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+pub struct WatchProviders {
+    #[serde(default)]
+    pub results: BTreeMap<Region, RegionWatchProviders>,
+}
+
+impl WatchProviders {
+    pub fn region(&self, region: Region) -> Option<&RegionWatchProviders> {
+        self.results.get(&region)
+    }
+
+    pub fn providers(&self, region: Region, monetization: MonetizationType) -> &[WatchProvider] {
+        self.region(region)
+            .map(|providers| providers.get(monetization))
+            .unwrap_or_default()
+    }
+
+    /// The services, that include the title in a subscription in `region`.
+    pub fn streaming(&self, region: Region) -> &[WatchProvider] {
+        self.providers(region, MonetizationType::Flatrate)
+    }
+
+    pub fn regions(&self) -> impl Iterator<Item = Region> + '_ {
+        self.results.keys().copied()
+    }
+}
+
+/// A region with watch provider data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchRegion {
+    pub region: Region,
+    pub english_name: String,
+    pub native_name: String,
+}
+
+/// Lists all watch providers for movies or tv series.
+pub struct WatchProviderListBuilder<'a> {
+    client: &'a TmdbClient,
+    media_type: MediaType,
+    language: Option<Cow<'a, str>>,
+    region: Option<Region>,
+}
+
+impl<'a> WatchProviderListBuilder<'a> {
+    pub fn new(client: &'a TmdbClient, media_type: MediaType) -> Self {
+        Self {
+            client,
+            media_type,
+            language: client.language.clone(),
+            region: None,
+        }
+    }
+
+    pub fn with_language(mut self, language: impl Into<Locale>) -> Self {
+        self.language = Some(language.into().to_string().into());
+        self
+    }
+
+    /// Only lists providers available in `region`.
+    pub fn with_region(mut self, region: Region) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// The providers, ordered by their display priority.
+    pub fn load(self) -> Result<Vec<WatchProvider>, Error> {
+        let watch_region = self.region.map(|region| region.to_string().into());
+        let mut providers: Vec<_> = match self.media_type {
+            MediaType::Movie => {
+                tmdb_easy_raw::parametrized_functions::watch_providers_movie_list_with_parameter(
+                    &self.client.client,
+                    &self.client.api_key,
+                    WatchProvidersMovieListParameter {
+                        language: self.language,
+                        watch_region,
+                    },
+                )?
+                .results
+                .into_iter()
+                .map(|provider| WatchProvider {
+                    id: WatchProviderId(provider.provider_id as _),
+                    name: provider.provider_name,
+                    logo_path: provider.logo_path,
+                    display_priority: provider.display_priority,
+                })
+                .collect()
+            }
+            MediaType::Tv => {
+                tmdb_easy_raw::parametrized_functions::watch_provider_tv_list_with_parameter(
+                    &self.client.client,
+                    &self.client.api_key,
+                    WatchProviderTvListParameter {
+                        language: self.language,
+                        watch_region,
+                    },
+                )?
+                .results
+                .into_iter()
+                .map(|provider| WatchProvider {
+                    id: WatchProviderId(provider.provider_id as _),
+                    name: provider.provider_name,
+                    logo_path: provider.logo_path,
+                    display_priority: provider.display_priority,
+                })
+                .collect()
+            }
+        };
+        providers.sort_by_key(|provider| provider.display_priority);
+        Ok(providers)
+    }
+}
+
+pub(crate) fn available_regions(client: &TmdbClient) -> Result<Vec<WatchRegion>, Error> {
+    let response =
+        tmdb_easy_raw::parametrized_functions::watch_providers_available_regions_with_parameter(
+            &client.client,
+            &client.api_key,
+            WatchProvidersAvailableRegionsParameter {
+                language: client.language.clone(),
+            },
+        )?;
+    Ok(response
+        .results
+        .into_iter()
+        .filter_map(|region| {
+            Some(WatchRegion {
+                region: region.iso_3166_1.parse().ok()?,
+                english_name: region.english_name,
+                native_name: region.native_name,
+            })
+        })
+        .collect())
+}
+
+#[test]
+fn watch_providers_by_region() {
+    let providers: WatchProviders = serde_json::from_str(
+        r#"{"id": 550, "results": {
+            "DE": {
+                "link": "https://www.themoviedb.org/movie/550/watch?locale=DE",
+                "flatrate": [{"provider_id": 8, "provider_name": "Netflix", "logo_path": "/n.jpg", "display_priority": 0}],
+                "buy": [{"provider_id": 2, "provider_name": "Apple TV", "display_priority": 4}]
+            },
+            "US": {"rent": [{"provider_id": 3, "provider_name": "Google Play Movies"}]}
+        }}"#,
+    )
+    .unwrap();
+    let de = "de".parse().unwrap();
+    assert_eq!(providers.streaming(de)[0].name, "Netflix");
+    assert_eq!(providers.streaming(de)[0].id, WatchProviderId(8));
+    assert!(providers.streaming("US".parse().unwrap()).is_empty());
+    assert!(providers.streaming("FR".parse().unwrap()).is_empty());
+    let offers: Vec<_> = providers
+        .region(de)
+        .unwrap()
+        .iter()
+        .map(|(m, _)| m)
+        .collect();
+    assert_eq!(offers, [MonetizationType::Flatrate, MonetizationType::Buy]);
+}